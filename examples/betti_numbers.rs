@@ -4,11 +4,9 @@ fn main() {
     for n in 1..=7 {
         for k in 1..n {
             let matroid = UniformMatroid::new(k, n).combinatorial_derived();
-            // the maximal elongation is n - k
-            for elongation in 0..=(matroid.n() - matroid.k()) {
-                let elongated_matroid = matroid.elongate(elongation);
-
-                println!("U_{}{}^({}): {}", k, n, elongation, elongated_matroid.betti());
+            // the elongations are the members of the Higgs family from rank k and up
+            for (elongation, elongated) in matroid.higgs_family().skip(matroid.k()).enumerate() {
+                println!("U_{}{}^({}): {}", k, n, elongation, elongated.betti());
             }
         }
     }
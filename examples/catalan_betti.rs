@@ -0,0 +1,17 @@
+use matroids::matroid::{CatalanMatroid, Matroid};
+
+fn main() {
+    for m in 2..=4 {
+        let matroid = CatalanMatroid::new(m);
+        println!("C_{}: {}", m, matroid.betti());
+
+        let derived = matroid.combinatorial_derived();
+        println!(
+            "derived C_{}: rank {} on {} elements, {} bases",
+            m,
+            derived.k(),
+            derived.n(),
+            derived.bases().len()
+        );
+    }
+}
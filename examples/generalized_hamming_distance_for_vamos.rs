@@ -4,14 +4,14 @@ fn main() {
     let matroid = Vamos::new();
 
     println!("Vamos matroid:");
-    for i in 1..=(matroid.n() - matroid.k()) {
-        println!("{} {}", i, matroid.generalized_hamming_distance(i).unwrap());
+    for (i, d) in matroid.weight_hierarchy().iter().enumerate() {
+        println!("{} {}", i + 1, d);
     }
 
     let dual = matroid.dual();
 
     println!("Dual Vamos matroid:");
-    for i in 1..=(dual.n() - dual.k()) {
-        println!("{} {}", i, dual.generalized_hamming_distance(i).unwrap());
+    for (i, d) in dual.weight_hierarchy().iter().enumerate() {
+        println!("{} {}", i + 1, d);
     }
 }
@@ -0,0 +1,23 @@
+use matroids::matroid::{MatrixMatroid, Matroid};
+
+fn main() {
+    // six points in the plane, as the vectors (x, y, 1)
+    // the points 0, 1 and 2 are on the line y = x, and the points 2, 3 and 4 on the line x = 1
+    let points: [&[f64]; 6] = [
+        &[-1.0, -1.0, 1.0],
+        &[0.5, 0.5, 1.0],
+        &[1.0, 1.0, 1.0],
+        &[1.0, -0.25, 1.0],
+        &[1.0, 2.5, 1.0],
+        &[-0.75, 2.0, 1.0],
+    ];
+
+    let matroid = MatrixMatroid::from_real_columns(&points).unwrap();
+
+    println!("rank: {}, bases: {}", matroid.k(), matroid.bases().len());
+    println!("lines with three points:");
+    for circuit in matroid.circuits().iter().filter(|c| c.size() == 3) {
+        let points: Vec<usize> = circuit.into();
+        println!("  {:?}", points);
+    }
+}
@@ -38,14 +38,15 @@ fn main() {
 
     let combinatorial_derived = matroid.combinatorial_derived();
 
-    for circuit in combinatorial_derived.circuits() {
-        let c: Vec<_> = circuit.into();
-        for i in c {
-            print!("{}", alphabet[i]);
-        }
-        print!(", ");
-    }
-    println!();
+    let labels: Vec<String> = alphabet[..combinatorial_derived.n()]
+        .iter()
+        .map(|c| c.to_string())
+        .collect();
+    let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+    println!(
+        "{}",
+        combinatorial_derived.format_sets(&combinatorial_derived.circuits(), &labels)
+    );
 
     fn info<M: Matroid>(m: &M) {
         println!("Got matroid or rank: {} on {} elements", m.k(), m.n());
@@ -1,11 +1,10 @@
 use std::fmt::Display;
 use std::iter::repeat;
 
-use rayon::prelude::*;
-
 use crate::field::Rational;
 use crate::matrix::{DynMatrix, Matrix};
 use crate::matroid::Matroid;
+use crate::parallel::*;
 use crate::set::SetIterator;
 
 use num_bigint::BigInt;
@@ -105,6 +104,41 @@ impl BettiNumbers {
         res
     }
 
+    /// Calculate every betti number directly from the sum over the subsets of the ground set,
+    /// without solving the Herzog-Kühl equations.
+    /// This is slower than [`BettiNumbers::new`], but does not rely on the equations having a
+    /// unique solution, so it can be used to verify the result of the faster method.
+    pub fn new_direct<M: Matroid + Sync>(matroid: &M) -> Self {
+        let n = matroid.n();
+        let k = n - matroid.k();
+
+        let contributions: Vec<(usize, usize, usize)> = SetIterator::new(n)
+            .par_bridge()
+            .filter(|s| matroid.is_cycle(s) && !s.is_empty())
+            .map(|s| (matroid.nullity(&s), s.size(), matroid.betti_num(&s)))
+            .filter(|(_, _, b)| *b != 0)
+            .collect();
+
+        let mut known_bettis = vec![(0, 0, 1)];
+        for (i, j, b) in contributions {
+            match known_bettis
+                .iter_mut()
+                .find(|(ip, jp, _)| *ip == i && *jp == j)
+            {
+                Some((_, _, sum)) => *sum += b,
+                None => known_bettis.push((i, j, b)),
+            }
+        }
+
+        BettiNumbers {
+            matrix: DynMatrix::new(k, 1),
+            key: Vec::new(),
+            known_bettis,
+            k,
+            n,
+        }
+    }
+
     fn fill_matrix(mut self) -> Self {
         for (idx, (i, j)) in self.key.iter() {
             for s in 0..self.k {
@@ -175,8 +209,22 @@ impl BettiNumbers {
         }
         res
     }
+
+    /// checks if the two lists of betti numbers are the same, independently of how they were
+    /// calculated
+    pub fn agrees_with(&self, other: &BettiNumbers) -> bool {
+        self.betti_numbers() == other.betti_numbers()
+    }
 }
 
+impl PartialEq for BettiNumbers {
+    fn eq(&self, other: &Self) -> bool {
+        self.agrees_with(other)
+    }
+}
+
+impl Eq for BettiNumbers {}
+
 impl Display for BettiNumbers {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "0 \\leftarrow S / I")?;
@@ -206,6 +254,7 @@ mod tests {
     use super::*;
 
     use crate::matroid::examples::{matroid_1, matroid_2};
+    use crate::matroid::UniformMatroid;
 
     #[test]
     fn from_ex62() {
@@ -249,5 +298,21 @@ mod tests {
         let betti_n = BettiNumbers::new(&n);
 
         assert_eq!(betti_m.betti_numbers(), betti_n.betti_numbers());
+        assert!(betti_m == betti_n);
+    }
+
+    #[test]
+    fn direct() {
+        let u36 = UniformMatroid::new(3, 6);
+
+        let betti = BettiNumbers::new(&u36);
+        let direct = BettiNumbers::new_direct(&u36);
+
+        assert!(betti == direct);
+        assert!(direct.agrees_with(&betti));
+
+        let m = matroid_1();
+        assert!(BettiNumbers::new(&m) == BettiNumbers::new_direct(&m));
+        assert!(BettiNumbers::new(&m) != BettiNumbers::new(&u36));
     }
 }
@@ -1,5 +1,8 @@
+use num_bigint::BigInt;
 use num_integer::{gcd, Integer};
 
+use tinyfield::prime_field::{PrimeField, PrimeFieldElt};
+
 use std::{
     fmt::Display,
     ops::{Add, Div, Mul, Neg, Sub},
@@ -81,6 +84,61 @@ impl<I: Integer + Clone> Rational<I> {
             denominator: I::one(),
         }
     }
+
+    /// The fraction numerator / denominator, in lowest terms.
+    /// Is None if the denominator is zero.
+    pub fn new(numerator: I, denominator: I) -> Option<Self> {
+        if denominator == I::zero() {
+            return None;
+        }
+
+        Some(
+            Rational {
+                numerator,
+                denominator,
+            }
+            .simplify(),
+        )
+    }
+}
+
+impl Rational<BigInt> {
+    /// The exact value of a finite float as a rational number.
+    /// Is None if the float is infinite or NaN.
+    pub fn from_f64(x: f64) -> Option<Self> {
+        if !x.is_finite() {
+            return None;
+        }
+
+        let bits = x.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1 << 52) - 1);
+        // subnormal numbers do not have the implicit leading one
+        let (mantissa, exponent) = if exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), exponent - 1075)
+        };
+
+        let mut numerator = BigInt::from(mantissa);
+        let mut denominator = BigInt::from(1);
+        if exponent >= 0 {
+            numerator <<= exponent as usize;
+        } else {
+            denominator <<= (-exponent) as usize;
+        }
+        if x.is_sign_negative() {
+            numerator = -numerator;
+        }
+
+        Some(
+            Rational {
+                numerator,
+                denominator,
+            }
+            .simplify(),
+        )
+    }
 }
 
 impl<I: Integer + Clone + Display> Display for Rational<I> {
@@ -167,3 +225,227 @@ impl<I: Integer + From<u8> + Clone> From<u8> for Rational<I> {
         }
     }
 }
+
+/// A finite field, where all the elements can be listed
+pub trait FiniteField:
+    Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + From<u8>
+    + PartialEq
+{
+    /// all the elements of the field, starting with zero and one
+    fn elements() -> Vec<Self>;
+}
+
+/// The elements of the prime fields from tinyfield, like `PrimeFieldElt<GF2>`
+impl<F: PrimeField> FiniteField for PrimeFieldElt<F> {
+    fn elements() -> Vec<Self> {
+        (0..F::CHARACTERISTIC).map(Self::from).collect()
+    }
+}
+
+/// The prime field with P elements, P has to be a prime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fp<const P: u8>(u8);
+
+/// The field with three elements
+pub type GF3 = Fp<3>;
+
+impl<const P: u8> Fp<P> {
+    fn inverse(self) -> Self {
+        assert!(self.0 != 0);
+        // P is small, so just search for the inverse
+        (1..P).map(Fp).find(|x| *x * self == Fp(1)).unwrap()
+    }
+}
+
+impl<const P: u8> Display for Fp<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u8> From<u8> for Fp<P> {
+    fn from(n: u8) -> Self {
+        Fp(n % P)
+    }
+}
+
+impl<const P: u8> Add for Fp<P> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Fp(((self.0 as u16 + other.0 as u16) % P as u16) as u8)
+    }
+}
+
+impl<const P: u8> Neg for Fp<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fp((P - self.0) % P)
+    }
+}
+
+impl<const P: u8> Sub for Fp<P> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<const P: u8> Mul for Fp<P> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Fp(((self.0 as u16 * other.0 as u16) % P as u16) as u8)
+    }
+}
+
+impl<const P: u8> Div for Fp<P> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other.inverse()
+    }
+}
+
+impl<const P: u8> FiniteField for Fp<P> {
+    fn elements() -> Vec<Self> {
+        (0..P).map(Fp).collect()
+    }
+}
+
+/// The field with four elements, {0, 1, a, a + 1} where a^2 = a + 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GF4(u8);
+
+impl GF4 {
+    /// the generator a of the field
+    #[allow(non_upper_case_globals)]
+    pub const a: GF4 = GF4(2);
+
+    /// multiplication table, where the bits of an element are the coefficients of 1 and a
+    const MUL: [[u8; 4]; 4] = [[0, 0, 0, 0], [0, 1, 2, 3], [0, 2, 3, 1], [0, 3, 1, 2]];
+
+    fn inverse(self) -> Self {
+        assert!(self.0 != 0);
+        GF4([0, 1, 3, 2][self.0 as usize])
+    }
+}
+
+impl Display for GF4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", ["0", "1", "a", "a+1"][self.0 as usize])
+    }
+}
+
+impl From<u8> for GF4 {
+    fn from(n: u8) -> Self {
+        GF4(n & 1)
+    }
+}
+
+impl Add for GF4 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, other: Self) -> Self {
+        GF4(self.0 ^ other.0)
+    }
+}
+
+impl Neg for GF4 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl Sub for GF4 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, other: Self) -> Self {
+        GF4(self.0 ^ other.0)
+    }
+}
+
+impl Mul for GF4 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        GF4(Self::MUL[self.0 as usize][other.0 as usize])
+    }
+}
+
+impl Div for GF4 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other.inverse()
+    }
+}
+
+impl FiniteField for GF4 {
+    fn elements() -> Vec<Self> {
+        (0..4).map(GF4).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tinyfield::GF2;
+
+    fn check_field<F: FiniteField + std::fmt::Debug>() {
+        let elements = F::elements();
+        let zero = F::from(0);
+        let one = F::from(1);
+        assert!(elements[0] == zero && elements[1] == one);
+
+        for &a in elements.iter() {
+            assert!(a + zero == a && a * one == a && a + -a == zero);
+            for &b in elements.iter() {
+                assert!(a + b == b + a && a * b == b * a);
+                assert!((a - b == zero) == (a == b));
+                if b != zero {
+                    assert!((a / b) * b == a);
+                    assert!((a / b == one) == (a == b));
+                }
+                for &c in elements.iter() {
+                    assert!(a * (b + c) == a * b + a * c);
+                    assert!((a * b) * c == a * (b * c));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn field_axioms() {
+        check_field::<PrimeFieldElt<GF2>>();
+        check_field::<GF3>();
+        check_field::<Fp<5>>();
+        check_field::<GF4>();
+    }
+
+    #[test]
+    fn rational_from_f64() {
+        let half = Rational::from(BigInt::from(1)) / Rational::from(BigInt::from(2));
+        assert!(Rational::from_f64(0.5) == Some(half.clone()));
+        assert!(Rational::from_f64(-0.5) == Some(-half));
+        assert!(Rational::from_f64(3.0).and_then(|r| r.to_integer()) == Some(BigInt::from(3)));
+        assert!(Rational::from_f64(0.0) == Some(Rational::from(BigInt::from(0))));
+        assert!(Rational::from_f64(f64::NAN).is_none());
+        assert!(Rational::from_f64(f64::INFINITY).is_none());
+    }
+}
@@ -3,6 +3,10 @@
 //! There is an optional feature, `progress`, which shows progress bars when calculating the
 //! combinatorial derived of non-fast matroids. Warning: This slows the code significantly.
 //!
+//! The default feature `parallel` does the heavy calculations in parallel using rayon. It can be
+//! disabled (with `--no-default-features`) on targets where rayon is not available, and then
+//! everything is calculated on a single thread.
+//!
 //! # Examples
 //!
 //! Calculation of the betti numbers of a uniform matroid on 5 elements of rank 3:
@@ -62,14 +66,19 @@
 //! ```
 
 extern crate postcard;
+extern crate rand;
+#[cfg(feature = "parallel")]
 extern crate rayon;
 extern crate serde;
+extern crate serde_json;
 extern crate tinyfield;
 
 pub mod matrix;
 pub mod matroid;
 pub mod betti_nums;
+pub mod field;
+pub mod prelude;
 pub mod set;
 
+mod parallel;
 mod utils;
-mod field;
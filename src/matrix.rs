@@ -52,6 +52,29 @@ where
         a
     }
 
+    /// Calculate the rank of the submatrix given by the columns, using scratch as the space for
+    /// the row reduction.
+    /// The scratch matrix is only reallocated if it has the wrong number of rows, or too few
+    /// columns, so reusing it for many queries avoids allocating a new matrix for every query.
+    fn rank_of_columns_into(&self, columns: &[usize], scratch: &mut Self) -> usize {
+        if scratch.num_rows() != self.num_rows() || scratch.num_cols() < columns.len() {
+            *scratch = Self::new(self.num_rows(), columns.len());
+        }
+
+        for i in 0..self.num_rows() {
+            for (j, &c) in columns.iter().enumerate() {
+                scratch[(i, j)] = self[(i, c)].clone();
+            }
+            // zero columns does not change the rank
+            for j in columns.len()..scratch.num_cols() {
+                scratch[(i, j)] = E::from(0u8);
+            }
+        }
+
+        scratch.gauss_jordan();
+        scratch.rank()
+    }
+
     /// Swap two columns in the matrix
     fn swap_cols(&mut self, a: usize, b: usize) {
         // for each place in the columns, swap the elements
@@ -397,4 +420,39 @@ mod tests {
 
         assert!(a.rank() == 3);
     }
+
+    #[test]
+    fn rank_of_columns_into() {
+        use tinyfield::prime_field::PrimeField;
+        use tinyfield::GF2;
+
+        let one = GF2::one;
+        let zer = GF2::zero;
+        // the parity check matrix of the Hamming code
+        let hamming = DynMatrix::from_rows(&[
+            &[one, zer, zer, one, one, zer, one],
+            &[zer, one, zer, one, zer, one, one],
+            &[zer, zer, one, zer, one, one, one],
+        ])
+        .unwrap();
+
+        let mut scratch = DynMatrix::new(0, 0);
+        for columns in [
+            vec![0, 1, 2, 3, 4, 5, 6],
+            vec![0, 1, 3],
+            vec![3, 4],
+            vec![2, 4, 6],
+            vec![5],
+            vec![6, 5, 4, 3],
+            vec![],
+        ] {
+            let mut expected = hamming.subset_matrix(&columns);
+            expected.gauss_jordan();
+
+            assert_eq!(
+                hamming.rank_of_columns_into(&columns, &mut scratch),
+                expected.rank()
+            );
+        }
+    }
 }
@@ -2,6 +2,8 @@ use crate::set::Set;
 
 use super::Matroid;
 
+use std::collections::HashSet;
+
 #[derive(Debug)]
 pub struct BasesMatroid {
     n: usize,
@@ -13,13 +15,64 @@ impl BasesMatroid {
     #[allow(unused)]
     /// Create a matroid from a list of bases.
     /// Every base should have k elements (the rank of the matroid), and the cadrinality of the
-    /// ground set is n.
+    /// ground set is n. This is not checked in release builds, use
+    /// [`verify_basis_exchange`](super::verify::verify_basis_exchange) for bases from the user.
     pub fn new(bases: Vec<Set>, n: usize, k: usize) -> Self {
         debug_assert!(k <= n);
         debug_assert!(bases.iter().all(|&x| x.size() == k));
         Self { bases, n, k }
     }
 
+    /// Create a matroid from the list of all its independent sets.
+    /// The bases are the independent sets of the largest cardinality.
+    /// Is None if the sets are not the independent sets of a matroid on n elements, i.e. if the
+    /// empty set is missing, if a subset of an independent set is missing (the sets are not
+    /// closed under taking subsets), or if the exchange axiom fails: for independent sets I and J
+    /// with |I| < |J|, there should be an element e of J not in I such that I + e is independent.
+    /// The bases are kept in the order they are given in.
+    pub fn from_independent_sets(independent_sets: &[Set], n: usize) -> Option<Self> {
+        let independent: HashSet<usize> = independent_sets.iter().map(usize::from).collect();
+
+        let ground_set = Set::of_size(n);
+        let hereditary = independent_sets.iter().all(|set| {
+            set.difference(&ground_set).is_empty()
+                && set
+                    .bits()
+                    .all(|e| independent.contains(&usize::from(set.remove_element(e))))
+        });
+        if !independent.contains(&0) || !hereditary {
+            return None;
+        }
+
+        for i in independent_sets {
+            for j in independent_sets.iter().filter(|j| j.size() > i.size()) {
+                let exchange = j
+                    .difference(i)
+                    .bits()
+                    .any(|e| independent.contains(&usize::from(i.add_element(e))));
+                if !exchange {
+                    return None;
+                }
+            }
+        }
+
+        let k = independent_sets.iter().map(|s| s.size()).max().unwrap_or(0);
+        // skip repeated sets, without changing the order
+        let mut seen = HashSet::new();
+        let bases = independent_sets
+            .iter()
+            .copied()
+            .filter(|s| s.size() == k && seen.insert(*s))
+            .collect();
+
+        Some(Self::new(bases, n, k))
+    }
+
+    /// the bases the matroid was created with
+    pub(crate) fn stored_bases(&self) -> &[Set] {
+        &self.bases
+    }
+
     /// calculate the rank of a subset given a list of bases
     /// It is assumed that all the bases are the same size
     pub fn rank_of_subset_given_bases(subset: &Set, bases: &[Set]) -> usize {
@@ -52,3 +105,54 @@ impl Matroid for BasesMatroid {
         Self::rank_of_subset_given_bases(subset, &self.bases)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::UniformMatroid;
+    use crate::set::SetIterator;
+
+    #[test]
+    fn from_independent_sets() {
+        let u24 = UniformMatroid::new(2, 4);
+        let independent: Vec<Set> = SetIterator::new(4)
+            .filter(|s| u24.is_independent(s))
+            .collect();
+
+        let matroid = BasesMatroid::from_independent_sets(&independent, 4).unwrap();
+        assert_eq!(matroid.k(), 2);
+        assert!(matroid.is_equal(&u24));
+        // the bases are in the order of the input, and repeated sets are only included once
+        let mut repeated = independent.clone();
+        repeated.reverse();
+        repeated.push(0b0011.into());
+        let matroid = BasesMatroid::from_independent_sets(&repeated, 4).unwrap();
+        let expected: Vec<Set> = u24.bases().into_iter().rev().collect();
+        assert_eq!(matroid.stored_bases(), expected);
+
+        // only the empty set is independent
+        let matroid = BasesMatroid::from_independent_sets(&[Set::empty()], 3).unwrap();
+        assert!(matroid.is_equal(&UniformMatroid::new(0, 3)));
+    }
+
+    #[test]
+    fn invalid_independent_sets() {
+        // the empty set is missing
+        assert!(BasesMatroid::from_independent_sets(&[0b1.into()], 2).is_none());
+
+        // {0, 1} is independent, but {1} is not
+        let sets = [Set::empty(), 0b01.into(), 0b11.into()];
+        assert!(BasesMatroid::from_independent_sets(&sets, 2).is_none());
+
+        // {2} can not be extended by an element of {0, 1}
+        let sets = [
+            Set::empty(),
+            0b001.into(),
+            0b010.into(),
+            0b100.into(),
+            0b011.into(),
+        ];
+        assert!(BasesMatroid::from_independent_sets(&sets, 3).is_none());
+    }
+}
@@ -0,0 +1,92 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// The Catalan matroid C_m, the lattice path matroid on 2m elements whose bases are the Dyck
+/// paths of length 2m. A set of m elements is a basis if it is the set of up steps of a path
+/// that never goes below its starting height, i.e. the i-th smallest element (counting from 0)
+/// is at most 2i. There are Catalan number many bases.
+///
+/// As a lattice path matroid it is the transversal matroid of the intervals [i, 2i] for
+/// i = 0, ..., m - 1, which is what the rank is calculated from.
+#[derive(Debug, Clone)]
+pub struct CatalanMatroid {
+    m: usize,
+}
+
+impl CatalanMatroid {
+    /// The Catalan matroid of rank m on 2m elements.
+    pub fn new(m: usize) -> Self {
+        CatalanMatroid { m }
+    }
+}
+
+impl Matroid for CatalanMatroid {
+    fn rank(&self, subset: &Set) -> usize {
+        // match the elements in increasing order, each to the unused interval ending first that
+        // contains it. For intervals this greedy matching is maximal.
+        let mut used = vec![false; self.m];
+        let mut rank = 0;
+
+        for e in subset.bits() {
+            // the intervals containing e are [i, 2i] for e / 2 <= i <= e, and they end in the
+            // same order as they start
+            if let Some(i) = (e.div_ceil(2)..=e.min(self.m.saturating_sub(1))).find(|&i| !used[i]) {
+                used[i] = true;
+                rank += 1;
+            }
+        }
+
+        rank
+    }
+
+    fn k(&self) -> usize {
+        self.m
+    }
+
+    fn n(&self) -> usize {
+        2 * self.m
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::BasesMatroid;
+    use crate::set::SetIterator;
+
+    /// the sets of up steps of the Dyck paths of length 2m
+    fn dyck_paths(m: usize) -> Vec<Set> {
+        SetIterator::new(2 * m)
+            .size_limit(m)
+            .equal()
+            .filter(|s| s.bits().enumerate().all(|(i, e)| e <= 2 * i))
+            .collect()
+    }
+
+    #[test]
+    fn catalan_numbers() {
+        let bases: Vec<usize> = (1..=5)
+            .map(|m| CatalanMatroid::new(m).bases().len())
+            .collect();
+        assert_eq!(bases, vec![1, 2, 5, 14, 42]);
+    }
+
+    #[test]
+    fn dyck_path_bases() {
+        for m in 1..=4 {
+            let catalan = CatalanMatroid::new(m);
+            let dyck = BasesMatroid::new(dyck_paths(m), 2 * m, m);
+
+            assert!(catalan.is_equal(&dyck));
+            assert!(SetIterator::new(2 * m).all(|s| catalan.rank(&s) == dyck.rank(&s)));
+        }
+
+        assert_eq!(CatalanMatroid::new(3).check_rank_axioms(), Ok(()));
+        // the first step of a Dyck path is always an up step, and the last one a down step
+        let catalan = CatalanMatroid::new(4);
+        assert!(catalan.bases().iter().all(|b| b.contains_element(0)));
+        assert_eq!(catalan.rank(&Set::from(1 << 7)), 0);
+    }
+}
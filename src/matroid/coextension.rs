@@ -0,0 +1,74 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// The free coextension of a matroid, the dual of the free extension of the dual.
+///
+/// A new element p (with index n) is added, and the rank goes up by one. A set X of the
+/// original elements has rank min(|X|, r(X) + 1), so deleting p gives the elongation by one (the
+/// Higgs lift), and X + p has rank r(X) + 1, so contracting p gives back the original matroid.
+pub struct FreeCoextension<'a, M: Matroid> {
+    matroid: &'a M,
+}
+
+impl<'a, M: Matroid> FreeCoextension<'a, M> {
+    /// create the free coextension of the matroid
+    pub fn new(matroid: &'a M) -> Self {
+        FreeCoextension { matroid }
+    }
+}
+
+impl<'a, M: Matroid> Matroid for FreeCoextension<'a, M> {
+    fn rank(&self, subset: &Set) -> usize {
+        let new_element = self.matroid.n();
+        let original = subset.remove_element(new_element);
+        let r = self.matroid.rank(&original);
+
+        if subset.contains_element(new_element) {
+            r + 1
+        } else {
+            original.size().min(r + 1)
+        }
+    }
+
+    fn k(&self) -> usize {
+        self.matroid.k() + 1
+    }
+
+    fn n(&self) -> usize {
+        self.matroid.n() + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::examples::fano;
+    use crate::matroid::UniformMatroid;
+
+    #[test]
+    fn dual_of_free_extension() {
+        let fano = fano();
+        let dual = fano.dual();
+        let extended = dual.free_extension();
+        assert!(fano.free_coextension().is_equal(&extended.dual()));
+
+        let u24 = UniformMatroid::new(2, 4);
+        assert!(u24.free_coextension().is_equal(&UniformMatroid::new(3, 5)));
+        assert_eq!(u24.free_coextension().check_rank_axioms(), Ok(()));
+    }
+
+    #[test]
+    fn minors() {
+        let fano = fano();
+        let coextension = fano.free_coextension();
+        let p = Set::empty().add_element(7);
+
+        // deleting the new element is the elongation by one, contracting it gives the original
+        let (deleted, contracted) = coextension.delete_contract(7);
+        assert!(deleted.is_equal(&fano.elongate(1)));
+        assert!(contracted.is_equal(&fano));
+        assert_eq!(coextension.rank(&p), 1);
+    }
+}
@@ -3,8 +3,7 @@ use std::sync::atomic::AtomicUsize;
 
 use super::{BasesMatroid, Matroid};
 
-use rayon::prelude::*;
-
+use crate::parallel::*;
 use crate::set::{Set, SetIterator};
 
 use dashmap::DashSet;
@@ -38,6 +37,12 @@ macro_rules! min {
 
 /// Do the epsilon operation on the circuits
 fn epsilon(dependents: &[Set], rank: usize) -> Vec<Set> {
+    // with fewer than two dependents there are no pairs to eliminate from. This happens when the
+    // matroid has at most one circuit, e.g. a free matroid or a matroid with many coloops.
+    if dependents.len() < 2 {
+        return dependents.to_vec();
+    }
+
     let dependent = DashSet::new();
 
     // the next variables are to do with progress reporting
@@ -176,7 +181,7 @@ fn inclusion_minimal(subsets: &[Set]) -> Vec<Set> {
         .collect()
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CombinatorialDerived {
     rank: usize,
     elements: Vec<Set>,
@@ -283,6 +288,12 @@ impl CombinatorialDerived {
         }
     }
 
+    /// The circuits of the original matroid, where the element i of the derived matroid is the
+    /// circuit elements()[i].
+    pub fn elements(&self) -> &[Set] {
+        &self.elements
+    }
+
     /// returns the union of all circuits in the subset
     pub fn circuit_union(&self, subset: &Set) -> Set {
         subset.union_of_sets(&self.elements)
@@ -297,6 +308,50 @@ impl CombinatorialDerived {
     }
 }
 
+/// Iterator over the iterated combinatorial derived matroids δM, δ²M, ... of a matroid.
+///
+/// Each derived matroid is computed from the previous one, and yielded as soon as it is found, so
+/// the caller can store it before the next (usually much more expensive) one is computed. The
+/// elements of δ^(i+1)M are the circuits of δ^iM, see [`CombinatorialDerived::elements`]. The
+/// iteration stops after the given depth, or after the first empty derived matroid, since every
+/// later one is empty too.
+pub struct CombinatorialDerivedIter<'a, M: Matroid + Sync> {
+    matroid: &'a M,
+    previous: Option<CombinatorialDerived>,
+    remaining: usize,
+}
+
+impl<'a, M: Matroid + Sync> CombinatorialDerivedIter<'a, M> {
+    /// create the iterator over the first depth derived matroids of the matroid
+    pub fn new(matroid: &'a M, depth: usize) -> Self {
+        CombinatorialDerivedIter {
+            matroid,
+            previous: None,
+            remaining: depth,
+        }
+    }
+}
+
+impl<'a, M: Matroid + Sync> Iterator for CombinatorialDerivedIter<'a, M> {
+    type Item = CombinatorialDerived;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let derived = match &self.previous {
+            None => CombinatorialDerived::from_matroid(self.matroid),
+            Some(previous) if previous.n() == 0 => return None,
+            Some(previous) => CombinatorialDerived::from_matroid(previous),
+        };
+        self.remaining -= 1;
+        self.previous = Some(derived.clone());
+
+        Some(derived)
+    }
+}
+
 impl<M: Matroid + Sync> From<&M> for CombinatorialDerived {
     fn from(matroid: &M) -> Self {
         CombinatorialDerived::from_matroid(matroid)
@@ -395,6 +450,43 @@ mod tests {
         assert!(derived.is_equal(&derived_uniform));
     }
 
+    #[test]
+    fn iterated() {
+        // δU(5, 6) = U(1, 1), which has no circuits
+        let derived: Vec<CombinatorialDerived> =
+            CombinatorialDerivedIter::new(&UniformMatroid::new(5, 6), 5).collect();
+        assert_eq!(derived.len(), 2);
+        assert!(derived[0].is_equal(&UniformMatroid::new(1, 1)));
+        assert_eq!(derived[0].elements(), &[Set::of_size(6)]);
+        assert_eq!((derived[1].n(), derived[1].k()), (0, 0));
+
+        let u24 = UniformMatroid::new(2, 4);
+        let mut iter = u24.combinatorial_derived_iter(2);
+        let first = iter.next().unwrap();
+        assert!(first.is_equal(&CombinatorialDerived::from(&u24)));
+        let second = iter.next().unwrap();
+        assert!(second.is_equal(&CombinatorialDerived::from(&first)));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn few_circuits() {
+        use crate::matroid::BasesMatroid;
+
+        // a parallel pair together with four coloops has a single circuit
+        let bases = vec![0b011111.into(), 0b101111.into()];
+        let matroid = BasesMatroid::new(bases, 6, 5);
+        let derived = CombinatorialDerived::from(&matroid);
+        assert_eq!((derived.n(), derived.k()), (1, 1));
+
+        // four coloops and a loop, so the only circuit is the loop
+        let matroid = BasesMatroid::new(vec![0b01111.into()], 5, 4);
+        let derived = CombinatorialDerived::from(&matroid);
+        assert_eq!((derived.n(), derived.k()), (1, 1));
+
+        assert!(epsilon(&[], 2).is_empty());
+    }
+
     #[test]
     fn inclusion_minimal_1() {
         let mut a: Vec<Set> = vec![0b0111.into(), 0b1111.into(), 0b1110.into()];
@@ -415,7 +507,6 @@ mod tests {
         assert!(contains_same_elems!(res, expected))
     }
 
-
     #[test]
     fn uniform_2_6() {
         // this matroid is fast, but has nullity 4, so there are dependent sets that are not in
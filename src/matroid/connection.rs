@@ -0,0 +1,288 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// The parallel connection of two matroids along a basepoint.
+///
+/// The basepoint e1 of M1 is identified with the basepoint e2 of M2. The elements of M1 keep
+/// their labels (so the basepoint is e1), and the other elements of M2 get the labels n1,
+/// n1 + 1, ... in order. The circuits are the circuits of M1 and M2, and the unions
+/// (C1 - p) ∪ (C2 - p) for circuits C1 and C2 through the basepoint p.
+pub struct ParallelConnection<'a, M1: Matroid, M2: Matroid> {
+    first: &'a M1,
+    second: &'a M2,
+    gluing: Gluing,
+    k: usize,
+}
+
+/// Create the parallel connection of m1 and m2, where e1 in m1 is identified with e2 in m2.
+/// See [`ParallelConnection`] for the labeling of the elements.
+pub fn parallel_connection<'a, M1: Matroid, M2: Matroid>(
+    m1: &'a M1,
+    e1: usize,
+    m2: &'a M2,
+    e2: usize,
+) -> ParallelConnection<'a, M1, M2> {
+    debug_assert!(e1 < m1.n() && e2 < m2.n());
+    let gluing = Gluing {
+        n1: m1.n(),
+        e1,
+        n2: m2.n(),
+        e2,
+    };
+
+    let mut connection = ParallelConnection {
+        first: m1,
+        second: m2,
+        gluing,
+        k: 0,
+    };
+    connection.k = connection.rank(&Set::of_size(gluing.n()));
+    connection
+}
+
+impl<'a, M1: Matroid, M2: Matroid> Matroid for ParallelConnection<'a, M1, M2> {
+    fn rank(&self, subset: &Set) -> usize {
+        parallel_rank(
+            &self.gluing,
+            |s| self.first.rank(s),
+            |s| self.second.rank(s),
+            subset,
+        )
+    }
+
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    fn n(&self) -> usize {
+        self.gluing.n()
+    }
+}
+
+/// The series connection of two matroids along a basepoint, the dual of the parallel connection
+/// of the duals.
+///
+/// The elements are labeled as in [`ParallelConnection`]. The circuits are the circuits of M1 and
+/// M2 that avoid the basepoint p, and the unions C1 ∪ C2 for circuits C1 and C2 through p.
+pub struct SeriesConnection<'a, M1: Matroid, M2: Matroid> {
+    first: &'a M1,
+    second: &'a M2,
+    gluing: Gluing,
+    k: usize,
+}
+
+/// Create the series connection of m1 and m2, where e1 in m1 is identified with e2 in m2.
+/// See [`ParallelConnection`] for the labeling of the elements.
+pub fn series_connection<'a, M1: Matroid, M2: Matroid>(
+    m1: &'a M1,
+    e1: usize,
+    m2: &'a M2,
+    e2: usize,
+) -> SeriesConnection<'a, M1, M2> {
+    debug_assert!(e1 < m1.n() && e2 < m2.n());
+    let gluing = Gluing {
+        n1: m1.n(),
+        e1,
+        n2: m2.n(),
+        e2,
+    };
+
+    let mut connection = SeriesConnection {
+        first: m1,
+        second: m2,
+        gluing,
+        k: 0,
+    };
+    connection.k = connection.rank(&Set::of_size(gluing.n()));
+    connection
+}
+
+impl<'a, M1: Matroid, M2: Matroid> Matroid for SeriesConnection<'a, M1, M2> {
+    fn rank(&self, subset: &Set) -> usize {
+        // the rank of the parallel connection of the duals
+        let dual_rank = |subset: &Set| {
+            parallel_rank(
+                &self.gluing,
+                |s| dual_rank(self.first, s),
+                |s| dual_rank(self.second, s),
+                subset,
+            )
+        };
+
+        let ground_set = Set::of_size(self.gluing.n());
+        subset.size() + dual_rank(&ground_set.difference(subset)) - dual_rank(&ground_set)
+    }
+
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    fn n(&self) -> usize {
+        self.gluing.n()
+    }
+}
+
+/// the rank of a subset in the dual of the matroid
+fn dual_rank<M: Matroid>(matroid: &M, subset: &Set) -> usize {
+    let ground_set = Set::of_size(matroid.n());
+    subset.size() + matroid.rank(&ground_set.difference(subset)) - matroid.k()
+}
+
+/// How the ground sets of two matroids are glued together along a basepoint
+#[derive(Debug, Clone, Copy)]
+struct Gluing {
+    n1: usize,
+    e1: usize,
+    n2: usize,
+    e2: usize,
+}
+
+impl Gluing {
+    fn n(&self) -> usize {
+        self.n1 + self.n2 - 1
+    }
+
+    /// split a subset of the glued ground set into the parts in the two matroids, where the
+    /// basepoint is in both parts if it is in the subset
+    fn split(&self, subset: &Set) -> (Set, Set) {
+        let first = subset.intersect(&Set::of_size(self.n1));
+        let second = subset
+            .bits()
+            .filter(|&e| e >= self.n1)
+            .map(|e| e - self.n1)
+            .map(|e| if e < self.e2 { e } else { e + 1 })
+            .fold(Set::empty(), |acc, e| acc.add_element(e));
+
+        if first.contains_element(self.e1) {
+            (first, second.add_element(self.e2))
+        } else {
+            (first, second)
+        }
+    }
+}
+
+/// the rank of a subset of the parallel connection, given the rank functions of the two parts
+fn parallel_rank(
+    gluing: &Gluing,
+    rank1: impl Fn(&Set) -> usize,
+    rank2: impl Fn(&Set) -> usize,
+    subset: &Set,
+) -> usize {
+    let (x1, x2) = gluing.split(subset);
+    let p1 = Set::empty().add_element(gluing.e1);
+    let p2 = Set::empty().add_element(gluing.e2);
+    let (with_p1, with_p2) = (x1.union(&p1), x2.union(&p2));
+    let base = rank1(&with_p1) + rank2(&with_p2);
+
+    // if the basepoint is a loop in one of the matroids, the parallel connection is the direct
+    // sum of that matroid and the other matroid with the basepoint contracted
+    if rank1(&p1) == 0 {
+        return base - rank2(&p2);
+    }
+    if rank2(&p2) == 0 {
+        return base - 1;
+    }
+
+    // the basepoint is in the closure of the subset if it is in the closure of one of the parts
+    let spans_basepoint = x1.contains_element(gluing.e1)
+        || rank1(&x1) == rank1(&with_p1)
+        || rank2(&x2) == rank2(&with_p2);
+    if spans_basepoint {
+        base - 1
+    } else {
+        base - 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::examples::fano;
+    use crate::matroid::UniformMatroid;
+
+    #[test]
+    fn two_triangles() {
+        // two triangles sharing an edge, the graphic matroid of K4 minus an edge
+        let triangle = UniformMatroid::new(2, 3);
+        let connection = parallel_connection(&triangle, 0, &triangle, 1);
+
+        assert_eq!((connection.n(), connection.k()), (5, 3));
+        assert_eq!(connection.check_rank_axioms(), Ok(()));
+        assert_eq!(connection.bases().len(), 8);
+
+        // the two triangles, and the 4-cycle around them
+        let mut circuits = connection.circuits();
+        circuits.sort_by_key(|c| usize::from(*c));
+        assert_eq!(
+            circuits,
+            vec![Set::from(0b00111), Set::from(0b11001), Set::from(0b11110)]
+        );
+    }
+
+    #[test]
+    fn parallel_element() {
+        // connecting with two parallel elements adds an element parallel to the basepoint
+        let fano = fano();
+        let parallel = UniformMatroid::new(1, 2);
+        let connection = parallel_connection(&fano, 3, &parallel, 0);
+
+        assert_eq!((connection.n(), connection.k()), (8, 3));
+        assert_eq!(connection.rank(&Set::from(0b1000_1000)), 1);
+        assert!(connection.restrict(&Set::of_size(7)).is_equal(&fano));
+    }
+
+    #[test]
+    fn series() {
+        // two triangles in series is a 5-cycle
+        let triangle = UniformMatroid::new(2, 3);
+        let connection = series_connection(&triangle, 0, &triangle, 1);
+        assert!(connection.is_equal(&UniformMatroid::new(4, 5)));
+
+        // connecting with two elements in series subdivides the basepoint
+        let fano = fano();
+        let coparallel = UniformMatroid::new(1, 2);
+        let connection = series_connection(&fano, 3, &coparallel, 0);
+        assert_eq!((connection.n(), connection.k()), (8, 4));
+        assert_eq!(connection.check_rank_axioms(), Ok(()));
+    }
+
+    #[test]
+    fn duality() {
+        let fano = fano();
+        let u24 = UniformMatroid::new(2, 4);
+        let u13 = UniformMatroid::new(1, 3);
+        // the basepoint 0 is a coloop in the free matroid and a loop in U(0, 2)
+        let free = UniformMatroid::new(2, 2);
+        let empty = UniformMatroid::new(0, 2);
+
+        fn check_pair<M1: Matroid, M2: Matroid>(m1: &M1, e1: usize, m2: &M2, e2: usize) {
+            let (dual1, dual2) = (m1.dual(), m2.dual());
+            let series = series_connection(m1, e1, m2, e2);
+            let parallel = parallel_connection(&dual1, e1, &dual2, e2);
+            assert!(series.dual().is_equal(&parallel));
+
+            let parallel = parallel_connection(m1, e1, m2, e2);
+            let series = series_connection(&dual1, e1, &dual2, e2);
+            assert!(parallel.dual().is_equal(&series));
+        }
+
+        check_pair(&fano, 0, &u24, 2);
+        check_pair(&u13, 1, &fano, 6);
+        check_pair(&free, 0, &u24, 0);
+        check_pair(&u24, 3, &empty, 0);
+    }
+
+    #[test]
+    fn loop_basepoint() {
+        // the basepoint is a loop in the first matroid, so the second one is contracted by it
+        let with_loop = UniformMatroid::new(0, 1);
+        let u24 = UniformMatroid::new(2, 4);
+        let connection = parallel_connection(&with_loop, 0, &u24, 0);
+
+        assert_eq!((connection.n(), connection.k()), (4, 1));
+        assert_eq!(connection.rank(&Set::from(0b1)), 0);
+        assert_eq!(connection.check_rank_axioms(), Ok(()));
+    }
+}
@@ -0,0 +1,325 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// The lattice of cyclic flats (the flats that are unions of circuits) of a matroid, with their
+/// ranks.
+///
+/// The cyclic flats with their ranks determine the matroid, and are often far fewer than the
+/// bases, so this is the most compact description of most matroids. In the lattice, the join of
+/// two cyclic flats is the closure of their union, and the meet is the largest cyclic flat in
+/// their intersection.
+#[derive(Debug, Clone)]
+pub struct CyclicFlatsLattice {
+    n: usize,
+    /// the cyclic flats with their ranks, ordered by rank
+    cyclic_flats: Vec<(Set, usize)>,
+}
+
+impl CyclicFlatsLattice {
+    /// Create the lattice of cyclic flats of a matroid on n elements, from the cyclic flats and
+    /// their ranks.
+    ///
+    /// Is None if the sets and ranks do not satisfy the axioms of Bonin and de Mier:
+    /// - (Z0) the sets form a lattice under inclusion,
+    /// - (Z1) the smallest set has rank 0,
+    /// - (Z2) for sets X ⊂ Y, 0 < r(Y) - r(X) < |Y \ X|,
+    /// - (Z3) for incomparable sets X and Y,
+    ///   r(X) + r(Y) >= r(X ∨ Y) + r(X ∧ Y) + |(X ∩ Y) \ (X ∧ Y)|.
+    pub fn new(mut cyclic_flats_with_ranks: Vec<(Set, usize)>, n: usize) -> Option<Self> {
+        let flats = &cyclic_flats_with_ranks;
+        let ground_set = Set::of_size(n);
+        if flats
+            .iter()
+            .any(|(f, _)| !f.difference(&ground_set).is_empty())
+        {
+            return None;
+        }
+
+        // (Z0) and (Z1): there is a smallest set, with rank 0
+        let bottom = smallest(flats.iter().copied())?;
+        if bottom.1 != 0 {
+            return None;
+        }
+
+        for (i, &(x, rx)) in flats.iter().enumerate() {
+            for &(y, ry) in flats.iter().skip(i + 1) {
+                if x == y {
+                    return None;
+                }
+
+                let valid = match (x <= y, y <= x) {
+                    // (Z2)
+                    (true, _) => ry > rx && ry - rx < y.difference(&x).size(),
+                    (_, true) => rx > ry && rx - ry < x.difference(&y).size(),
+                    // (Z0) and (Z3)
+                    _ => {
+                        let union = x.union(&y);
+                        let intersection = x.intersect(&y);
+                        let join = smallest(flats.iter().copied().filter(|(f, _)| union <= *f));
+                        let meet =
+                            largest(flats.iter().copied().filter(|(f, _)| *f <= intersection));
+
+                        match (join, meet) {
+                            (Some((_, r_join)), Some((meet, r_meet))) => {
+                                rx + ry >= r_join + r_meet + intersection.difference(&meet).size()
+                            }
+                            _ => false,
+                        }
+                    }
+                };
+                if !valid {
+                    return None;
+                }
+            }
+        }
+
+        cyclic_flats_with_ranks.sort_by_key(|&(f, r)| (r, usize::from(f)));
+        Some(CyclicFlatsLattice {
+            n,
+            cyclic_flats: cyclic_flats_with_ranks,
+        })
+    }
+
+    /// the cyclic flats with their ranks, ordered by rank
+    pub fn cyclic_flats(&self) -> &[(Set, usize)] {
+        &self.cyclic_flats
+    }
+
+    /// the smallest cyclic flat, the set of loops
+    pub fn bottom(&self) -> Set {
+        self.cyclic_flats[0].0
+    }
+
+    /// the largest cyclic flat, the complement of the set of coloops
+    pub fn top(&self) -> Set {
+        self.cyclic_flats[self.cyclic_flats.len() - 1].0
+    }
+
+    /// checks if the set is one of the cyclic flats of the lattice
+    pub fn contains(&self, x: &Set) -> bool {
+        self.cyclic_flats.iter().any(|(f, _)| f == x)
+    }
+
+    /// The join of two cyclic flats, the smallest cyclic flat containing both of them.
+    /// Is None if one of the sets is not a cyclic flat of the lattice.
+    pub fn join(&self, x: &Set, y: &Set) -> Option<Set> {
+        if !self.contains(x) || !self.contains(y) {
+            return None;
+        }
+
+        let union = x.union(y);
+        smallest(
+            self.cyclic_flats
+                .iter()
+                .copied()
+                .filter(|(f, _)| union <= *f),
+        )
+        .map(|(f, _)| f)
+    }
+
+    /// The meet of two cyclic flats, the largest cyclic flat contained in both of them.
+    /// Is None if one of the sets is not a cyclic flat of the lattice.
+    pub fn meet(&self, x: &Set, y: &Set) -> Option<Set> {
+        if !self.contains(x) || !self.contains(y) {
+            return None;
+        }
+
+        let intersection = x.intersect(y);
+        largest(
+            self.cyclic_flats
+                .iter()
+                .copied()
+                .filter(|(f, _)| *f <= intersection),
+        )
+        .map(|(f, _)| f)
+    }
+
+    /// The covering relations of the lattice, the pairs (X, Y) of cyclic flats with X ⊂ Y and
+    /// no cyclic flat strictly between them.
+    pub fn covers(&self) -> Vec<(Set, Set)> {
+        let flats = &self.cyclic_flats;
+        flats
+            .iter()
+            .flat_map(|&(x, _)| {
+                flats
+                    .iter()
+                    .filter(move |&&(y, _)| {
+                        x < y
+                            && !flats
+                                .iter()
+                                .any(|&(z, _)| z != x && z != y && x <= z && z <= y)
+                    })
+                    .map(move |&(y, _)| (x, y))
+            })
+            .collect()
+    }
+
+    /// the matroid with these cyclic flats
+    pub fn matroid(&self) -> CyclicFlatsMatroid {
+        let mut matroid = CyclicFlatsMatroid {
+            n: self.n,
+            k: 0,
+            cyclic_flats: self.cyclic_flats.clone(),
+        };
+        matroid.k = matroid.rank(&Set::of_size(self.n));
+
+        matroid
+    }
+}
+
+/// A matroid given by its cyclic flats (the flats that are unions of circuits) and their ranks.
+///
+/// The cyclic flats with their ranks determine the matroid, and are often far fewer than the
+/// bases. The rank of a set X is the minimum of r(F) + |X \ F| over the cyclic flats F.
+#[derive(Debug, Clone)]
+pub struct CyclicFlatsMatroid {
+    n: usize,
+    k: usize,
+    cyclic_flats: Vec<(Set, usize)>,
+}
+
+impl CyclicFlatsMatroid {
+    /// Create the matroid on n elements with the given cyclic flats and ranks.
+    /// Is None if the sets and ranks do not satisfy the axioms of Bonin and de Mier, see
+    /// [`CyclicFlatsLattice::new`].
+    pub fn new(cyclic_flats_with_ranks: Vec<(Set, usize)>, n: usize) -> Option<Self> {
+        Some(CyclicFlatsLattice::new(cyclic_flats_with_ranks, n)?.matroid())
+    }
+}
+
+/// the set contained in every other set, if there is one
+fn smallest(sets: impl Iterator<Item = (Set, usize)> + Clone) -> Option<(Set, usize)> {
+    sets.clone()
+        .find(|(candidate, _)| sets.clone().all(|(s, _)| *candidate <= s))
+}
+
+/// the set containing every other set, if there is one
+fn largest(sets: impl Iterator<Item = (Set, usize)> + Clone) -> Option<(Set, usize)> {
+    sets.clone()
+        .find(|(candidate, _)| sets.clone().all(|(s, _)| s <= *candidate))
+}
+
+impl Matroid for CyclicFlatsMatroid {
+    fn rank(&self, subset: &Set) -> usize {
+        self.cyclic_flats
+            .iter()
+            .map(|(flat, rank)| rank + subset.difference(flat).size())
+            .fold(subset.size(), usize::min)
+    }
+
+    fn cyclic_flats(&self) -> Vec<(Set, usize)> {
+        self.cyclic_flats.clone()
+    }
+
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    fn n(&self) -> usize {
+        self.n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::examples::{fano, matroid_1};
+    use crate::matroid::{BasesMatroid, UniformMatroid, Vamos};
+
+    #[test]
+    fn round_trip() {
+        let fano = fano();
+        let cyclic_flats = fano.cyclic_flats();
+        // the empty set, the seven lines and the ground set
+        assert_eq!(cyclic_flats.len(), 9);
+        assert!(CyclicFlatsMatroid::new(cyclic_flats, 7)
+            .unwrap()
+            .is_equal(&fano));
+
+        let vamos = Vamos::new();
+        let matroid = CyclicFlatsMatroid::new(vamos.cyclic_flats(), 8).unwrap();
+        assert!(matroid.is_equal(&vamos));
+
+        let m = matroid_1();
+        let matroid = CyclicFlatsMatroid::new(m.cyclic_flats(), m.n()).unwrap();
+        assert!(matroid.is_equal(&m));
+    }
+
+    #[test]
+    fn coloops() {
+        // U(1, 2) together with a coloop, the ground set is not cyclic
+        let bases = vec![0b101.into(), 0b110.into()];
+        let matroid = BasesMatroid::new(bases, 3, 2);
+        let cyclic_flats = matroid.cyclic_flats();
+        assert_eq!(cyclic_flats, vec![(Set::empty(), 0), (Set::from(0b011), 1)]);
+
+        let from_flats = CyclicFlatsMatroid::new(cyclic_flats, 3).unwrap();
+        assert_eq!(from_flats.k(), 2);
+        assert!(from_flats.is_equal(&matroid));
+
+        let free = CyclicFlatsMatroid::new(vec![(Set::empty(), 0)], 4).unwrap();
+        assert!(free.is_equal(&UniformMatroid::new(4, 4)));
+    }
+
+    #[test]
+    fn lattice() {
+        let fano = fano();
+        let lattice = CyclicFlatsLattice::new(fano.cyclic_flats(), 7).unwrap();
+        assert_eq!(lattice.bottom(), Set::empty());
+        assert_eq!(lattice.top(), Set::of_size(7));
+        assert_eq!(lattice.cyclic_flats()[1].1, 2);
+
+        // two lines meet in a point, which is not cyclic, and span the plane
+        let (a, b) = (Set::from(0b0000111), Set::from(0b0011001));
+        assert_eq!(lattice.meet(&a, &b), Some(Set::empty()));
+        assert_eq!(lattice.join(&a, &b), Some(Set::of_size(7)));
+        assert!(lattice.contains(&a));
+        // a point is not a cyclic flat
+        assert_eq!(lattice.join(&a, &0b1000000.into()), None);
+
+        // every line covers the empty set and is covered by the plane
+        assert_eq!(lattice.covers().len(), 14);
+        assert!(lattice.matroid().is_equal(&fano));
+
+        // the Vámos matroid has two planes whose union is not a plane
+        let vamos = Vamos::new();
+        let lattice = CyclicFlatsLattice::new(vamos.cyclic_flats(), 8).unwrap();
+        assert_eq!(lattice.cyclic_flats().len(), 7);
+        assert_eq!(lattice.covers().len(), 10);
+
+        // a loop, a parallel pair and a coloop: sets with the coloop or without the loop are
+        // not cyclic flats
+        let bases = vec![0b1010.into(), 0b1100.into()];
+        let matroid = BasesMatroid::new(bases, 4, 2);
+        let lattice = CyclicFlatsLattice::new(matroid.cyclic_flats(), 4).unwrap();
+        assert_eq!(lattice.top(), Set::from(0b0111));
+        assert_eq!(lattice.join(&0b0111.into(), &0b1000.into()), None);
+        assert_eq!(lattice.meet(&0b0110.into(), &0b0110.into()), None);
+        assert_eq!(
+            lattice.meet(&0b0111.into(), &0b0001.into()),
+            Some(0b0001.into())
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        // no smallest set
+        let flats = vec![(Set::from(0b0111), 1), (Set::from(0b1110), 1)];
+        assert!(CyclicFlatsMatroid::new(flats, 4).is_none());
+
+        // (Z2): {0, 1, 2} would be independent with rank 3, so it is not cyclic
+        let flats = vec![(Set::empty(), 0), (Set::from(0b0111), 3)];
+        assert!(CyclicFlatsMatroid::new(flats, 4).is_none());
+
+        // (Z3): two lines meeting in two points
+        let flats = vec![
+            (Set::empty(), 0),
+            (Set::from(0b00111), 2),
+            (Set::from(0b11011), 2),
+            (Set::of_size(5), 3),
+        ];
+        assert!(CyclicFlatsMatroid::new(flats, 5).is_none());
+    }
+}
@@ -24,6 +24,10 @@ impl<'a, M: Matroid> Matroid for Dual<'a, M> {
     fn k(&self) -> usize {
         self.matroid.n() - self.matroid.k()
     }
+
+    fn bases_of_dual(&self) -> Option<Vec<Set>> {
+        Some(self.matroid.bases())
+    }
 }
 
 impl<'a, M: Matroid> From<&'a M> for Dual<'a, M> {
@@ -47,6 +51,8 @@ mod tests {
     use crate::matrix::DynMatrix;
     use crate::matroid::{MatrixMatroid, UniformMatroid};
 
+    use std::cell::Cell;
+
     use tinyfield::prime_field::PrimeField;
     use tinyfield::GF2;
 
@@ -69,6 +75,42 @@ mod tests {
         assert!(dual.is_equal(&u46));
     }
 
+    #[test]
+    fn self_dual() {
+        let u36 = UniformMatroid::new(3, 6);
+        assert!(u36.is_equal(&u36.dual()));
+        assert!(UniformMatroid::new(2, 6).is_equal(&UniformMatroid::new(4, 6).dual()));
+        assert!(!UniformMatroid::new(2, 6).is_equal(&u36.dual()));
+
+        /// U(3, 6), recording the largest subset it is asked for the rank of
+        struct Observed {
+            largest: Cell<usize>,
+        }
+
+        impl Matroid for Observed {
+            fn rank(&self, subset: &Set) -> usize {
+                self.largest.set(self.largest.get().max(subset.size()));
+                subset.size().min(3)
+            }
+
+            fn k(&self) -> usize {
+                3
+            }
+
+            fn n(&self) -> usize {
+                6
+            }
+        }
+
+        let observed = Observed {
+            largest: Cell::new(0),
+        };
+        assert!(u36.is_equal(&observed.dual()));
+        // only the bases of the inner matroid were used, the full check would ask for the rank of
+        // the complement of the empty set
+        assert_eq!(observed.largest.get(), 3);
+    }
+
     #[test]
     fn hamming_code() {
         let one = GF2::one;
@@ -1,6 +1,11 @@
+use crate::field::{FiniteField, GF3};
+use crate::matrix::{DynMatrix, Matrix};
 use crate::set::{Set, SetIterator};
 
-use super::BasesMatroid;
+use super::{BasesMatroid, MatrixMatroid};
+
+use tinyfield::prime_field::PrimeFieldElt;
+use tinyfield::GF2;
 
 /// This is the matroid M from exampe 6.2 in the paper "A generalization of weight polynomials to matroids"
 /// <https://doi.org/10.1016/j.disc.2015.10.005>
@@ -99,9 +104,164 @@ pub fn non_fast_matroid() -> BasesMatroid {
     BasesMatroid::new(bases, 6, 2)
 }
 
+/// The Fano matroid F_7, the matroid of the Fano plane PG(2, 2).
+/// The bases are the triples of points that are not on a common line.
+#[allow(unused)]
+pub fn fano() -> BasesMatroid {
+    let lines: [Set; 7] = [
+        0b0000111, 0b0011001, 0b1100001, 0b0101010, 0b1010010, 0b1001100, 0b0110100,
+    ]
+    .map(Set::from);
+
+    let bases = SetIterator::new(7)
+        .size_limit(3)
+        .equal()
+        .filter(|set| !lines.contains(set))
+        .collect();
+
+    BasesMatroid::new(bases, 7, 3)
+}
+
+/// The planes of the binary affine cube AG(3, 2), with element i as the vertex of the cube with
+/// the coordinates given by the three lowest bits of i.
+/// The first six are the faces, then the six diagonal planes in complementary pairs, and the two
+/// twisted planes (tetrahedra) at the end.
+const CUBE_PLANES: [usize; 14] = [
+    0b01010101, 0b10101010, 0b00110011, 0b11001100, 0b00001111, 0b11110000, // faces
+    0b10011001, 0b01100110, 0b11000011, 0b00111100, 0b10100101, 0b01011010, // diagonal planes
+    0b01101001, 0b10010110, // twisted planes
+];
+
+/// The rank 4 paving matroid on the vertices of the cube, where the given planes are the
+/// circuit-hyperplanes, and every other set of four elements is a basis.
+fn cube_matroid(planes: &[usize]) -> BasesMatroid {
+    let bases = SetIterator::new(8)
+        .size_limit(4)
+        .equal()
+        .filter(|set| !planes.contains(&usize::from(set)))
+        .collect();
+
+    BasesMatroid::new(bases, 8, 4)
+}
+
+/// The binary affine cube AG(3, 2), where the 14 affine planes are the circuit-hyperplanes.
+#[allow(unused)]
+pub fn ag32() -> BasesMatroid {
+    cube_matroid(&CUBE_PLANES)
+}
+
+/// R_8, the real affine cube. It is AG(3, 2) with the two twisted planes relaxed, so it has the
+/// six faces and the six diagonal planes as circuit-hyperplanes. It is ternary, but not binary.
+#[allow(unused)]
+pub fn r8() -> BasesMatroid {
+    cube_matroid(&CUBE_PLANES[..12])
+}
+
+/// F_8, the relaxation of one of the diagonal planes of R_8 (all choices are isomorphic).
+/// It is not representable over any field.
+#[allow(unused)]
+pub fn f8() -> BasesMatroid {
+    cube_matroid(&[&CUBE_PLANES[..6], &CUBE_PLANES[7..12]].concat())
+}
+
+/// Q_8, the relaxation of a second diagonal plane of F_8, chosen as the complement of the first
+/// one, so that Q_8 is identically self-dual. It is not representable over any field.
+#[allow(unused)]
+pub fn q8() -> BasesMatroid {
+    cube_matroid(&[&CUBE_PLANES[..6], &CUBE_PLANES[8..12]].concat())
+}
+
+/// L_8, AG(3, 2) with the six diagonal planes relaxed, so the circuit-hyperplanes are the six
+/// faces and the two twisted planes.
+#[allow(unused)]
+pub fn l8() -> BasesMatroid {
+    cube_matroid(&[&CUBE_PLANES[..6], &CUBE_PLANES[12..]].concat())
+}
+
+/// The matroid of the generator matrix [I | A] in standard form, with the entries of A given as
+/// integers that are mapped into the field.
+fn standard_form<F: FiniteField>(a: &[Vec<u8>]) -> MatrixMatroid<F> {
+    let k = a.len();
+    let mut matrix = DynMatrix::new(k, k + a[0].len());
+    for (i, row) in a.iter().enumerate() {
+        matrix[(i, i)] = F::from(1);
+        for (j, &entry) in row.iter().enumerate() {
+            matrix[(i, k + j)] = F::from(entry);
+        }
+    }
+
+    MatrixMatroid::from(matrix)
+}
+
+/// The matroid of the extended binary Golay code, the [24, 12, 8] code over GF(2).
+///
+/// The generator matrix is [I | B], where B is bordered by a zero in the corner and ones along the
+/// first row and column, and the remaining 11 x 11 block has a one in position (i, j) when j - i
+/// is zero or a quadratic residue modulo 11.
+#[allow(unused)]
+pub fn binary_golay() -> MatrixMatroid<PrimeFieldElt<GF2>> {
+    let residues = [1, 3, 4, 5, 9];
+    let b: Vec<Vec<u8>> = (0..12)
+        .map(|i| {
+            (0..12)
+                .map(|j| match (i, j) {
+                    (0, 0) => 0,
+                    (0, _) | (_, 0) => 1,
+                    _ => {
+                        let difference = (j + 11 - i) % 11;
+                        u8::from(difference == 0 || residues.contains(&difference))
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    standard_form(&b)
+}
+
+/// The matroid of the extended ternary Golay code, the [12, 6, 6] code over GF(3).
+///
+/// The generator matrix is [I | P], where P is the symmetric conference matrix of order 6.
+#[allow(unused)]
+pub fn ternary_golay() -> MatrixMatroid<GF3> {
+    let p = [
+        vec![0, 1, 1, 1, 1, 1],
+        vec![1, 0, 1, 2, 2, 1],
+        vec![1, 1, 0, 1, 2, 2],
+        vec![1, 2, 1, 0, 1, 2],
+        vec![1, 2, 2, 1, 0, 1],
+        vec![1, 1, 2, 2, 1, 0],
+    ];
+
+    standard_form(&p)
+}
+
+/// The matroid of the Reed-Muller code RM(r, m) over GF(2), of length 2^m.
+///
+/// The rows of the generator matrix are the evaluations of the monomials of degree at most r in
+/// m variables, and element i is the point whose coordinates are the m lowest bits of i.
+#[allow(unused)]
+pub fn reed_muller_matroid(r: usize, m: usize) -> MatrixMatroid<PrimeFieldElt<GF2>> {
+    let n = 1 << m;
+    // a monomial is the product of the variables in the set
+    let monomials: Vec<Set> = SetIterator::new(m).filter(|s| s.size() <= r).collect();
+
+    let mut matrix = DynMatrix::new(monomials.len(), n);
+    for (i, monomial) in monomials.iter().enumerate() {
+        for point in 0..n {
+            // the monomial is 1 at the points where all its variables are 1
+            if *monomial <= Set::from(point) {
+                matrix[(i, point)] = PrimeFieldElt::from(1);
+            }
+        }
+    }
+
+    MatrixMatroid::from(matrix)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::matroid::Matroid;
+    use crate::matroid::{Matroid, UniformMatroid};
 
     use super::*;
 
@@ -122,4 +282,75 @@ mod tests {
         assert!(!m1.is_equal(&m2));
         assert_ne!(m1.bases_series(), m2.bases_series());
     }
+
+    #[test]
+    fn cube_relaxations() {
+        // every plane of AG(3, 2) is a set of four vertices summing to zero
+        assert!(CUBE_PLANES.iter().all(|&p| {
+            let plane: Set = p.into();
+            plane.size() == 4 && plane.bits().fold(0, |acc, e| acc ^ e) == 0
+        }));
+
+        let cubes = [ag32(), r8(), f8(), q8(), l8()];
+        let bases: Vec<usize> = cubes.iter().map(|m| m.bases().len()).collect();
+        assert_eq!(bases, vec![56, 58, 59, 60, 62]);
+        assert!(cubes.iter().all(|m| m.check_rank_axioms().is_ok()));
+
+        // the complement of a circuit-hyperplane is a circuit-hyperplane, except in F_8
+        for (m, self_dual) in cubes.iter().zip([true, true, false, true, true]) {
+            assert_eq!(m.is_equal(&m.dual()), self_dual);
+        }
+        assert!(f8().is_isomorphic(&f8().dual()));
+    }
+
+    #[test]
+    fn cube_representability() {
+        // contracting a point of AG(3, 2) gives the Fano matroid
+        assert!(ag32().has_minor(&fano()));
+        assert!(!ag32().is_ternary());
+        assert!(r8().is_ternary());
+    }
+
+    #[test]
+    fn golay_codes() {
+        let ternary = ternary_golay();
+        assert_eq!(ternary.code_parameters(), (12, 6, Some(6)));
+        // the ternary Golay code is self-dual, so the matroid is too
+        assert_eq!(ternary.girth(), Some(6));
+
+        let binary = binary_golay();
+        assert_eq!((binary.n(), binary.k()), (24, 12));
+        // the second row is a codeword of weight 8, so its support is a cocircuit
+        let octad = Set::from(vec![1, 12, 13, 14, 16, 17, 18, 22]);
+        let ground_set = Set::of_size(24);
+        assert_eq!(binary.rank(&ground_set.difference(&octad)), 11);
+        for e in octad.bits() {
+            let smaller = octad.remove_element(e);
+            assert_eq!(binary.rank(&ground_set.difference(&smaller)), 12);
+        }
+        // the information set and its complement are both bases
+        assert!(binary.is_independent(&Set::of_size(12)));
+        assert!(binary.is_independent(&ground_set.difference(&Set::of_size(12))));
+    }
+
+    #[test]
+    fn reed_muller() {
+        // RM(1, 3) is the extended Hamming code, whose matroid is AG(3, 2)
+        let rm13 = reed_muller_matroid(1, 3);
+        assert_eq!(rm13.code_parameters(), (8, 4, Some(4)));
+        assert!(rm13.is_equal(&ag32()));
+
+        assert_eq!(
+            reed_muller_matroid(1, 4).code_parameters(),
+            (16, 5, Some(8))
+        );
+        assert_eq!(
+            reed_muller_matroid(2, 4).code_parameters(),
+            (16, 11, Some(4))
+        );
+
+        // RM(0, m) is the repetition code, and RM(m, m) is the whole space
+        assert!(reed_muller_matroid(0, 3).is_equal(&UniformMatroid::new(1, 8)));
+        assert!(reed_muller_matroid(3, 3).is_equal(&UniformMatroid::new(8, 8)));
+    }
 }
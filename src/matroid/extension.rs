@@ -0,0 +1,101 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// The principal extension of a matroid by a flat.
+///
+/// A new element (with index n) is added freely to the flat, i.e. it is placed in the span of the
+/// flat, but in general position with respect to this.
+pub struct PrincipalExtension<'a, M: Matroid> {
+    matroid: &'a M,
+    flat: Set,
+}
+
+impl<'a, M: Matroid> PrincipalExtension<'a, M> {
+    /// create the principal extension of the matroid by the flat
+    pub fn new(matroid: &'a M, flat: &Set) -> Self {
+        PrincipalExtension {
+            matroid,
+            flat: *flat,
+        }
+    }
+}
+
+impl<'a, M: Matroid> Matroid for PrincipalExtension<'a, M> {
+    fn rank(&self, subset: &Set) -> usize {
+        let new_element = self.matroid.n();
+        let original = subset.remove_element(new_element);
+        let r = self.matroid.rank(&original);
+
+        // the new element only increases the rank if the flat is not spanned by the subset
+        if subset.contains_element(new_element)
+            && self.matroid.rank(&original.union(&self.flat)) > r
+        {
+            r + 1
+        } else {
+            r
+        }
+    }
+
+    fn k(&self) -> usize {
+        self.matroid.k()
+    }
+
+    fn n(&self) -> usize {
+        self.matroid.n() + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::UniformMatroid;
+
+    #[test]
+    fn free_extension() {
+        let u23 = UniformMatroid::new(2, 3);
+        assert!(u23.free_extension().is_equal(&UniformMatroid::new(2, 4)));
+
+        // the new point is on no line of the Fano plane, so it is only in spanning circuits
+        let fano = crate::matroid::examples::fano();
+        let extended = fano.free_extension();
+        assert_eq!((extended.n(), extended.k()), (8, 3));
+        assert!(extended
+            .circuits()
+            .iter()
+            .filter(|c| c.contains_element(7))
+            .all(|c| c.size() == 4));
+
+        // extending by the flat of a line puts the new point on the line
+        let on_line = fano.principal_extension(&0b0000111.into());
+        assert!(on_line.is_circuit(&0b10000011.into()));
+    }
+
+    #[test]
+    fn point_on_line() {
+        let u23 = UniformMatroid::new(2, 3);
+        let u24 = UniformMatroid::new(2, 4);
+
+        let extended = u23.add_point_on_line(&Set::of_size(3)).unwrap();
+
+        assert!(extended.is_equal(&u24));
+    }
+
+    #[test]
+    fn point_on_line_not_a_line() {
+        let u34 = UniformMatroid::new(3, 4);
+
+        // a line in U(3, 4) gets a third point
+        let extended = u34.add_point_on_line(&0b0011.into()).unwrap();
+        assert!(extended.is_circuit(&0b10011.into()));
+
+        // a plane is not a line
+        assert!(u34.add_point_on_line(&0b0111.into()).is_err());
+
+        // a point is not a line, and neither is a non-closed set
+        let u24 = UniformMatroid::new(2, 4);
+        assert!(u24.add_point_on_line(&0b0001.into()).is_err());
+        assert!(u24.add_point_on_line(&0b0011.into()).is_err());
+    }
+}
@@ -0,0 +1,151 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// A matroid given by its lattice of flats.
+///
+/// The rank of a set is the rank of its closure, the smallest flat containing it, so the rank
+/// oracle looks through the flats from the lowest rank and up.
+#[derive(Debug, Clone)]
+pub struct FlatsMatroid {
+    n: usize,
+    flats_by_rank: Vec<Vec<Set>>,
+}
+
+impl FlatsMatroid {
+    /// Create the matroid on n elements where flats_by_rank[r] is the list of flats of rank r.
+    ///
+    /// Is None if the flats do not satisfy the flat axioms: there has to be exactly one flat of
+    /// rank 0 and the ground set has to be the only flat of the highest rank, the intersection
+    /// of two flats has to be a flat, every flat of positive rank has to contain a flat of one
+    /// rank less, and for every flat F the flats of one rank more that contain F have to
+    /// partition the elements outside F.
+    pub fn new(flats_by_rank: Vec<Vec<Set>>, n: usize) -> Option<Self> {
+        let ground_set = Set::of_size(n);
+        let all: Vec<Set> = flats_by_rank.iter().flatten().copied().collect();
+
+        let mut unique = all.clone();
+        unique.sort_by_key(|s| usize::from(*s));
+        unique.dedup();
+
+        if flats_by_rank.first().map(Vec::len) != Some(1)
+            || flats_by_rank.last() != Some(&vec![ground_set])
+            || unique.len() != all.len()
+            || all.iter().any(|f| !f.difference(&ground_set).is_empty())
+        {
+            return None;
+        }
+
+        let intersection_closed = all
+            .iter()
+            .all(|a| all.iter().all(|b| all.contains(&a.intersect(b))));
+        if !intersection_closed {
+            return None;
+        }
+
+        for rank in 1..flats_by_rank.len() {
+            let (lower, upper) = (&flats_by_rank[rank - 1], &flats_by_rank[rank]);
+
+            if !upper.iter().all(|g| lower.iter().any(|f| f < g)) {
+                return None;
+            }
+
+            for f in lower {
+                // the flats covering f, without the elements of f
+                let covers: Vec<Set> = upper
+                    .iter()
+                    .filter(|g| f < *g)
+                    .map(|g| g.difference(f))
+                    .collect();
+
+                let disjoint = covers
+                    .iter()
+                    .enumerate()
+                    .all(|(i, a)| covers[i + 1..].iter().all(|b| a.intersect(b).is_empty()));
+                let covering = covers.iter().fold(*f, |acc, c| acc.union(c)) == ground_set;
+                if !disjoint || !covering {
+                    return None;
+                }
+            }
+        }
+
+        Some(FlatsMatroid { n, flats_by_rank })
+    }
+}
+
+impl Matroid for FlatsMatroid {
+    fn rank(&self, subset: &Set) -> usize {
+        // the ground set contains every subset, so a flat is always found
+        self.flats_by_rank
+            .iter()
+            .position(|flats| flats.iter().any(|f| subset <= f))
+            .unwrap()
+    }
+
+    fn closure(&self, subset: &Set) -> Set {
+        // the flats containing the subset are closed under intersection, so the closure is the
+        // unique one of the lowest rank
+        *self
+            .flats_by_rank
+            .iter()
+            .flatten()
+            .find(|f| subset <= *f)
+            .unwrap()
+    }
+
+    fn flats_of_rank(&self, rank: usize) -> Vec<Set> {
+        self.flats_by_rank.get(rank).cloned().unwrap_or_default()
+    }
+
+    fn k(&self) -> usize {
+        self.flats_by_rank.len() - 1
+    }
+
+    fn n(&self) -> usize {
+        self.n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::examples::fano;
+    use crate::matroid::UniformMatroid;
+
+    fn lattice_of_flats<M: Matroid>(matroid: &M) -> Vec<Vec<Set>> {
+        (0..=matroid.k())
+            .map(|rank| matroid.flats_of_rank(rank))
+            .collect()
+    }
+
+    #[test]
+    fn from_flats() {
+        let fano = fano();
+        let matroid = FlatsMatroid::new(lattice_of_flats(&fano), 7).unwrap();
+        assert!(matroid.is_equal(&fano));
+        assert_eq!(matroid.closure(&Set::from(0b11)), Set::from(0b111));
+
+        let u36 = UniformMatroid::new(3, 6);
+        let matroid = FlatsMatroid::new(lattice_of_flats(&u36), 6).unwrap();
+        assert!(matroid.is_equal(&u36));
+        assert_eq!(matroid.check_rank_axioms(), Ok(()));
+    }
+
+    #[test]
+    fn invalid() {
+        // without one of the lines of the Fano plane, two points have no common line
+        let mut flats = lattice_of_flats(&fano());
+        flats[2].pop();
+        assert!(FlatsMatroid::new(flats, 7).is_none());
+
+        // the lines {0, 1, 2} and {0, 1, 3} intersect in {0, 1}, which is not a flat
+        let flats = vec![
+            vec![Set::empty()],
+            (0..4).map(|e| Set::empty().add_element(e)).collect(),
+            vec![0b0111.into(), 0b1011.into(), 0b1100.into()],
+            vec![Set::of_size(4)],
+        ];
+        assert!(FlatsMatroid::new(flats, 4).is_none());
+    }
+}
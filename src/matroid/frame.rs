@@ -0,0 +1,181 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// The frame matroid (bias matroid) of a gain graph over a cyclic group Z_m.
+///
+/// Every edge (u, v, g) has the gain g when it is traversed from u to v, and -g the other way.
+/// A cycle is balanced if the sum of the gains around it is zero, and a set of edges has rank
+/// |V| - b, where b is the number of connected components of the graph on all the vertices with
+/// the edges in the set that are balanced, i.e. contain no unbalanced cycle.
+/// An edge (u, u, g) is a loop of the graph, which is a loop of the matroid if g = 0, and an
+/// unbalanced loop (a point in general position on its vertex) otherwise.
+#[derive(Debug, Clone)]
+pub struct FrameMatroid {
+    vertices: usize,
+    order: usize,
+    edges: Vec<(usize, usize, usize)>,
+    k: usize,
+}
+
+impl FrameMatroid {
+    /// Create the frame matroid of the gain graph on the given number of vertices, with gains in
+    /// the cyclic group of the given order. Element i is the edge edges[i].
+    /// Is None if the order is zero, or if an edge has an endpoint or a gain out of range.
+    pub fn new(vertices: usize, order: usize, edges: &[(usize, usize, usize)]) -> Option<Self> {
+        if order == 0
+            || edges
+                .iter()
+                .any(|&(u, v, g)| u >= vertices || v >= vertices || g >= order)
+        {
+            return None;
+        }
+
+        let mut matroid = FrameMatroid {
+            vertices,
+            order,
+            edges: edges.to_vec(),
+            k: 0,
+        };
+        matroid.k = matroid.rank(&Set::of_size(edges.len()));
+
+        Some(matroid)
+    }
+
+    /// The Dowling geometry Q_r(Z_m) of rank r over the cyclic group of order m.
+    /// It is the frame matroid of the complete gain graph on r vertices, with one edge for every
+    /// pair of vertices and every gain, and an unbalanced loop (with gain 1) on every vertex.
+    /// The edges between the vertices i < j with the gains 0, ..., m - 1 come first, in
+    /// lexicographic order of (i, j), followed by the r loops.
+    /// Is None for the trivial group, which has no unbalanced loops (the Dowling geometry is then
+    /// the cycle matroid of the complete graph on r + 1 vertices).
+    pub fn dowling_geometry(rank: usize, order: usize) -> Option<Self> {
+        if order < 2 {
+            return None;
+        }
+
+        let mut edges: Vec<(usize, usize, usize)> = (0..rank)
+            .flat_map(|i| ((i + 1)..rank).flat_map(move |j| (0..order).map(move |g| (i, j, g))))
+            .collect();
+        edges.extend((0..rank).map(|v| (v, v, 1)));
+
+        Self::new(rank, order, &edges)
+    }
+
+    /// The number of vertices of the gain graph
+    pub fn vertices(&self) -> usize {
+        self.vertices
+    }
+
+    /// The edges (u, v, g) of the gain graph, where element i is the edge number i
+    pub fn edges(&self) -> &[(usize, usize, usize)] {
+        &self.edges
+    }
+
+    /// the number of balanced connected components of the graph with the edges in the subset
+    fn balanced_components(&self, subset: &Set) -> usize {
+        let mut adjacent: Vec<Vec<(usize, usize)>> = vec![Vec::new(); self.vertices];
+        for (u, v, g) in subset.bits().map(|e| self.edges[e]) {
+            adjacent[u].push((v, g));
+            if u != v {
+                adjacent[v].push((u, (self.order - g) % self.order));
+            }
+        }
+
+        // give every vertex a potential, so that the gain of an edge from u to v is the
+        // difference between the potentials of v and u if the component is balanced
+        let mut potential: Vec<Option<usize>> = vec![None; self.vertices];
+        let mut balanced = 0;
+        for start in 0..self.vertices {
+            if potential[start].is_some() {
+                continue;
+            }
+
+            potential[start] = Some(0);
+            let mut stack = vec![start];
+            let mut is_balanced = true;
+            while let Some(u) = stack.pop() {
+                let pu = potential[u].unwrap();
+                for &(v, g) in &adjacent[u] {
+                    let expected = (pu + g) % self.order;
+                    match potential[v] {
+                        Some(pv) => is_balanced &= pv == expected,
+                        None => {
+                            potential[v] = Some(expected);
+                            stack.push(v);
+                        }
+                    }
+                }
+            }
+
+            if is_balanced {
+                balanced += 1;
+            }
+        }
+
+        balanced
+    }
+}
+
+impl Matroid for FrameMatroid {
+    fn rank(&self, subset: &Set) -> usize {
+        self.vertices - self.balanced_components(subset)
+    }
+
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    fn n(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::{GraphicMatroid, UniformMatroid};
+
+    #[test]
+    fn trivial_gains() {
+        // with all gains zero, the frame matroid is the cycle matroid of the graph
+        let graph = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 0), (1, 1)];
+        let edges: Vec<_> = graph.iter().map(|&(u, v)| (u, v, 0)).collect();
+        let frame = FrameMatroid::new(4, 3, &edges).unwrap();
+
+        assert!(frame.is_equal(&GraphicMatroid::new(4, &graph).unwrap()));
+    }
+
+    #[test]
+    fn unbalanced_cycles() {
+        // two parallel edges with different gains form an unbalanced cycle, which is independent
+        let frame = FrameMatroid::new(2, 2, &[(0, 1, 0), (0, 1, 1)]).unwrap();
+        assert!(frame.is_equal(&UniformMatroid::new(2, 2)));
+
+        // a triangle is balanced if the gains sum to zero around it
+        let balanced = FrameMatroid::new(3, 3, &[(0, 1, 1), (1, 2, 1), (0, 2, 2)]).unwrap();
+        assert_eq!(balanced.k(), 2);
+        let unbalanced = FrameMatroid::new(3, 3, &[(0, 1, 1), (1, 2, 1), (0, 2, 1)]).unwrap();
+        assert_eq!(unbalanced.k(), 3);
+    }
+
+    #[test]
+    fn dowling_geometries() {
+        // Q_2(Z_3) is three links and two unbalanced loops on a line
+        let q2 = FrameMatroid::dowling_geometry(2, 3).unwrap();
+        assert!(q2.is_equal(&UniformMatroid::new(2, 5)));
+
+        // Q_3(Z_2) has two links for each of the three pairs of vertices, and three loops
+        let q3 = FrameMatroid::dowling_geometry(3, 2).unwrap();
+        assert_eq!((q3.n(), q3.k()), (9, 3));
+        assert_eq!(q3.check_rank_axioms(), Ok(()));
+        // the six links form the signed graph ±K_3, whose frame matroid is M(K_4)
+        let k4 = GraphicMatroid::new(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]).unwrap();
+        assert!(q3.restrict(&Set::of_size(6)).is_isomorphic(&k4));
+
+        assert!(FrameMatroid::dowling_geometry(3, 1).is_none());
+        assert!(FrameMatroid::new(2, 2, &[(0, 2, 0)]).is_none());
+        assert!(FrameMatroid::new(2, 2, &[(0, 1, 2)]).is_none());
+    }
+}
@@ -0,0 +1,145 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// The cycle matroid M(G) of a graph G, where the circuits are the cycles of the graph.
+///
+/// The rank of a set of edges is the number of vertices minus the number of connected components
+/// of the graph with those edges, found with a union-find. The flow polynomial of the graph is
+/// [`Matroid::flow_polynomial`], which already has the normalization for graphs.
+#[derive(Debug, Clone)]
+pub struct GraphicMatroid {
+    vertices: usize,
+    edges: Vec<(usize, usize)>,
+    k: usize,
+}
+
+impl GraphicMatroid {
+    /// Create the cycle matroid of the graph with the given number of vertices, where element i
+    /// is the edge edges[i]. An edge (u, u) is a loop.
+    /// Is None if an edge has an endpoint out of range.
+    pub fn new(vertices: usize, edges: &[(usize, usize)]) -> Option<Self> {
+        if edges.iter().any(|&(u, v)| u >= vertices || v >= vertices) {
+            return None;
+        }
+
+        let mut matroid = GraphicMatroid {
+            vertices,
+            edges: edges.to_vec(),
+            k: 0,
+        };
+        matroid.k = matroid.rank(&Set::of_size(edges.len()));
+
+        Some(matroid)
+    }
+
+    /// The number of vertices of the graph
+    pub fn vertices(&self) -> usize {
+        self.vertices
+    }
+
+    /// The edges of the graph, where element i is the edge number i
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    /// The number of connected components of the graph, including the isolated vertices
+    pub fn graph_components(&self) -> usize {
+        self.vertices - self.k
+    }
+
+    /// The chromatic polynomial P(G, t) = (-1)^(|V| - c) t^c T(1 - t, 0) of the graph, where c
+    /// is the number of connected components. Element i is the coefficient of t^i.
+    ///
+    /// P(G, t) is the number of proper colorings of the vertices with t colors, so it is zero if
+    /// the graph has a loop.
+    pub fn chromatic_polynomial(&self) -> Vec<i64> {
+        // the characteristic polynomial is (-1)^r(E) T(1 - t, 0), and r(E) = |V| - c
+        let mut chromatic = vec![0i64; self.graph_components()];
+        chromatic.extend(self.characteristic_polynomial());
+        chromatic
+    }
+}
+
+/// the representative of the set containing x, with path halving
+fn find(parent: &mut [usize], mut x: usize) -> usize {
+    while parent[x] != x {
+        parent[x] = parent[parent[x]];
+        x = parent[x];
+    }
+    x
+}
+
+impl Matroid for GraphicMatroid {
+    fn rank(&self, subset: &Set) -> usize {
+        // every edge joining two components lowers the number of components by one
+        let mut parent: Vec<usize> = (0..self.vertices).collect();
+        subset
+            .bits()
+            .map(|e| self.edges[e])
+            .filter(|&(u, v)| {
+                let (a, b) = (find(&mut parent, u), find(&mut parent, v));
+                parent[a] = b;
+                a != b
+            })
+            .count()
+    }
+
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    fn n(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(p: &[i64], t: i64) -> i64 {
+        p.iter().rev().fold(0, |acc, c| acc * t + c)
+    }
+
+    #[test]
+    fn cycle_matroid() {
+        let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 3), (4, 5)];
+        let graph = GraphicMatroid::new(6, &edges).unwrap();
+
+        // the triangle and the loop are the cycles, and the other two edges are bridges
+        assert_eq!(
+            graph.circuits(),
+            vec![Set::from(0b000111), Set::from(0b010000)]
+        );
+        assert_eq!(graph.k(), 4);
+        assert_eq!(graph.graph_components(), 2);
+        assert!(GraphicMatroid::new(2, &[(0, 2)]).is_none());
+    }
+
+    #[test]
+    fn chromatic_polynomial() {
+        // t (t - 1) (t - 2) (t - 3)
+        let k4 = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+        let k4 = GraphicMatroid::new(4, &k4).unwrap();
+        assert_eq!(k4.chromatic_polynomial(), vec![0, -6, 11, -6, 1]);
+        // the nowhere-zero flows of K_4
+        assert_eq!(k4.flow_polynomial(), vec![-6, 11, -6, 1]);
+
+        // the 5-cycle has (t - 1)^5 - (t - 1) colorings
+        let c5: Vec<_> = (0..5).map(|i| (i, (i + 1) % 5)).collect();
+        let c5 = GraphicMatroid::new(5, &c5).unwrap();
+        for t in 0..6 {
+            assert_eq!(
+                evaluate(&c5.chromatic_polynomial(), t),
+                (t - 1).pow(5) - (t - 1)
+            );
+        }
+
+        // an edge and an isolated vertex, and a graph with a loop
+        let edge = GraphicMatroid::new(3, &[(0, 1)]).unwrap();
+        assert_eq!(edge.chromatic_polynomial(), vec![0, 0, -1, 1]);
+        let with_loop = GraphicMatroid::new(2, &[(0, 1), (1, 1)]).unwrap();
+        assert!(with_loop.chromatic_polynomial().iter().all(|&c| c == 0));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// A member of the Higgs family of a matroid, i.e. a truncation or an elongation of it.
+///
+/// For a rank r below the rank k of the matroid this is the truncation, where a set X has rank
+/// min(r_M(X), r), and for r above k it is the elongation by r - k, where X has rank
+/// min(|X|, r_M(X) + r - k).
+pub struct HiggsLift<'a, M: Matroid> {
+    matroid: &'a M,
+    rank: usize,
+}
+
+impl<'a, M: Matroid> HiggsLift<'a, M> {
+    /// create the member of the Higgs family of the matroid that has the given rank
+    pub fn new(matroid: &'a M, rank: usize) -> Self {
+        debug_assert!(rank <= matroid.n());
+        HiggsLift { matroid, rank }
+    }
+}
+
+impl<'a, M: Matroid> Matroid for HiggsLift<'a, M> {
+    fn rank(&self, subset: &Set) -> usize {
+        let r = self.matroid.rank(subset);
+        let k = self.matroid.k();
+
+        if self.rank <= k {
+            r.min(self.rank)
+        } else {
+            subset.size().min(r + self.rank - k)
+        }
+    }
+
+    fn k(&self) -> usize {
+        self.rank
+    }
+
+    fn n(&self) -> usize {
+        self.matroid.n()
+    }
+}
+
+/// Iterator over the Higgs family of a matroid, from the rank 0 matroid to the free matroid.
+/// The i'th item has rank i, so it is a truncation for i < k, the matroid itself for i = k and
+/// the elongation by i - k for i > k.
+pub struct HiggsFamily<'a, M: Matroid> {
+    matroid: &'a M,
+    next_rank: usize,
+}
+
+impl<'a, M: Matroid> HiggsFamily<'a, M> {
+    /// create the iterator over the Higgs family of the matroid
+    pub fn new(matroid: &'a M) -> Self {
+        HiggsFamily {
+            matroid,
+            next_rank: 0,
+        }
+    }
+}
+
+impl<'a, M: Matroid> Iterator for HiggsFamily<'a, M> {
+    type Item = HiggsLift<'a, M>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_rank > self.matroid.n() {
+            return None;
+        }
+
+        let lift = HiggsLift::new(self.matroid, self.next_rank);
+        self.next_rank += 1;
+        Some(lift)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.matroid.n() + 1).saturating_sub(self.next_rank);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, M: Matroid> ExactSizeIterator for HiggsFamily<'a, M> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::examples::fano;
+    use crate::matroid::UniformMatroid;
+
+    #[test]
+    fn higgs_family() {
+        let fano = fano();
+        let family: Vec<_> = fano.higgs_family().collect();
+        assert_eq!(family.len(), 8);
+
+        for (rank, lift) in family.iter().enumerate() {
+            assert_eq!(lift.k(), rank);
+            assert_eq!(lift.check_rank_axioms(), Ok(()));
+        }
+
+        assert!(family[0].is_equal(&UniformMatroid::new(0, 7)));
+        // every pair of points is a basis of the truncation to rank 2
+        assert!(family[2].is_equal(&UniformMatroid::new(2, 7)));
+        assert!(family[3].is_equal(&fano));
+        for elongation in 0..=4 {
+            assert!(family[3 + elongation].is_equal(&fano.elongate(elongation)));
+        }
+        assert!(family[7].is_equal(&UniformMatroid::new(7, 7)));
+    }
+
+    #[test]
+    fn truncation() {
+        // the truncation of U(3, 5) to rank 2 is U(2, 5)
+        let u35 = UniformMatroid::new(3, 5);
+        let truncated = HiggsLift::new(&u35, 2);
+        assert!(truncated.is_equal(&UniformMatroid::new(2, 5)));
+    }
+}
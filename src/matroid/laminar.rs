@@ -0,0 +1,196 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// A laminar matroid, given by a laminar family of subsets with a capacity for each subset.
+/// A family is laminar if any two of its members are either disjoint, or one contains the other.
+/// A set is independent if it has at most c(A) elements in every member A of the family.
+///
+/// Partition matroids are the laminar matroids where the members are disjoint, and the uniform
+/// matroid U(k, n) is the laminar matroid with the ground set as the only member, with capacity k.
+#[derive(Debug, Clone)]
+pub struct LaminarMatroid {
+    n: usize,
+    k: usize,
+    /// the members of the family with their capacities, ordered by increasing size
+    members: Vec<(Set, usize)>,
+    /// the index of the smallest member strictly containing each member, if any
+    parents: Vec<Option<usize>>,
+}
+
+impl LaminarMatroid {
+    /// Create the laminar matroid on n elements from the family of (member, capacity).
+    /// If a member is listed several times, the smallest capacity is used.
+    /// Is None if the family is not laminar, or if a member has elements outside the ground set.
+    pub fn new(n: usize, family: &[(Set, usize)]) -> Option<Self> {
+        let ground_set = Set::of_size(n);
+        if family
+            .iter()
+            .any(|(member, _)| !member.difference(&ground_set).is_empty())
+        {
+            return None;
+        }
+
+        let mut members: Vec<(Set, usize)> = Vec::new();
+        for &(member, capacity) in family {
+            match members.iter_mut().find(|(m, _)| *m == member) {
+                Some((_, c)) => *c = (*c).min(capacity),
+                None => members.push((member, capacity)),
+            }
+        }
+        members.sort_by_key(|(member, _)| member.size());
+
+        for (i, (a, _)) in members.iter().enumerate() {
+            for (b, _) in members.iter().skip(i + 1) {
+                if !a.intersect(b).is_empty() && !a.difference(b).is_empty() {
+                    return None;
+                }
+            }
+        }
+
+        // the members are ordered by size, so the first later member containing a member is the
+        // smallest one
+        let parents = members
+            .iter()
+            .enumerate()
+            .map(|(i, (a, _))| {
+                members
+                    .iter()
+                    .enumerate()
+                    .skip(i + 1)
+                    .find(|(_, (b, _))| a <= b)
+                    .map(|(j, _)| j)
+            })
+            .collect();
+
+        let mut matroid = LaminarMatroid {
+            n,
+            k: 0,
+            members,
+            parents,
+        };
+        matroid.k = matroid.rank(&ground_set);
+
+        Some(matroid)
+    }
+
+    /// The partition matroid, where a set is independent if it has at most capacity elements in
+    /// each of the blocks. The blocks should be disjoint.
+    pub fn partition(n: usize, blocks: &[(Set, usize)]) -> Option<Self> {
+        if blocks.iter().enumerate().any(|(i, (a, _))| {
+            blocks[i + 1..]
+                .iter()
+                .any(|(b, _)| !a.intersect(b).is_empty())
+        }) {
+            return None;
+        }
+
+        Self::new(n, blocks)
+    }
+}
+
+impl Matroid for LaminarMatroid {
+    fn rank(&self, subset: &Set) -> usize {
+        // the children of a member are handled before the member, so the rank in a member is the
+        // elements not in any child, plus the ranks in the children, up to the capacity
+        let mut ranks = vec![0; self.members.len()];
+        let mut covered = vec![Set::empty(); self.members.len()];
+        let mut rank = 0;
+        let mut top_covered = Set::empty();
+
+        for (i, (member, capacity)) in self.members.iter().enumerate() {
+            let free = subset.intersect(member).difference(&covered[i]).size();
+            let member_rank = (free + ranks[i]).min(*capacity);
+
+            match self.parents[i] {
+                Some(parent) => {
+                    ranks[parent] += member_rank;
+                    covered[parent] = covered[parent].union(member);
+                }
+                None => {
+                    rank += member_rank;
+                    top_covered = top_covered.union(member);
+                }
+            }
+        }
+
+        rank + subset.difference(&top_covered).size()
+    }
+
+    fn is_independent(&self, subset: &Set) -> bool {
+        self.members
+            .iter()
+            .all(|(member, capacity)| subset.intersect(member).size() <= *capacity)
+    }
+
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    fn n(&self) -> usize {
+        self.n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::betti_nums::BettiNumbers;
+    use crate::matroid::{BasesMatroid, OracleMatroid, UniformMatroid};
+    use crate::set::SetIterator;
+
+    #[test]
+    fn uniform() {
+        let laminar = LaminarMatroid::new(6, &[(Set::of_size(6), 3)]).unwrap();
+
+        assert_eq!(laminar.k(), 3);
+        assert!(laminar.is_equal(&UniformMatroid::new(3, 6)));
+    }
+
+    #[test]
+    fn nested() {
+        let family = [
+            (Set::from(0b0000011), 1),
+            (Set::from(0b0001111), 2),
+            (Set::from(0b0110000), 1),
+            (Set::from(0b1111111), 3),
+        ];
+        let laminar = LaminarMatroid::new(7, &family).unwrap();
+
+        // the same matroid, with the rank found greedily from the independent sets
+        let oracle = OracleMatroid::from_independence_oracle(7, 3, |s: &Set| {
+            family.iter().all(|(a, c)| s.intersect(a).size() <= *c)
+        });
+
+        assert_eq!(laminar.k(), 3);
+        assert!(SetIterator::new(7).all(|s| laminar.rank(&s) == oracle.rank(&s)));
+        assert!(laminar.is_equal(&BasesMatroid::new(oracle.bases(), 7, 3)));
+
+        assert!(BettiNumbers::new(&laminar) == BettiNumbers::new_direct(&laminar));
+    }
+
+    #[test]
+    fn partition() {
+        // two blocks with capacity one is the direct sum of two copies of U(1, 2)
+        let blocks = [(Set::from(0b0011), 1), (Set::from(0b1100), 1)];
+        let partition = LaminarMatroid::partition(4, &blocks).unwrap();
+
+        assert_eq!(partition.k(), 2);
+        assert_eq!(partition.bases().len(), 4);
+        assert!(!partition.is_connected());
+
+        // elements outside every block are coloops
+        let partition = LaminarMatroid::partition(5, &blocks).unwrap();
+        assert_eq!(partition.k(), 3);
+        assert!(partition.bases().iter().all(|b| b.contains_element(4)));
+
+        assert!(LaminarMatroid::partition(4, &[(0b0011.into(), 1), (0b0110.into(), 1)]).is_none());
+    }
+
+    #[test]
+    fn not_laminar() {
+        assert!(LaminarMatroid::new(4, &[(0b0011.into(), 1), (0b0110.into(), 1)]).is_none());
+        assert!(LaminarMatroid::new(2, &[(0b0111.into(), 1)]).is_none());
+    }
+}
@@ -1,14 +1,21 @@
+use crate::field::{FiniteField, Rational};
 use crate::matrix::{DynMatrix, Matrix};
-use crate::set::Set;
+use crate::set::{Set, SetIterator};
+
+use num_bigint::BigInt;
+use num_integer::binomial;
+use rand::seq::SliceRandom;
+use rand::Rng;
 
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
+use super::representation::find_representation;
 use super::Matroid;
 
 #[derive(Debug)]
 pub struct MatrixMatroid<E>
 where
-    E: Copy
+    E: Clone
         + Add<Output = E>
         + Sub<Output = E>
         + Mul<Output = E>
@@ -23,7 +30,7 @@ where
 
 impl<E> Matroid for MatrixMatroid<E>
 where
-    E: Copy
+    E: Clone
         + Add<Output = E>
         + Sub<Output = E>
         + Mul<Output = E>
@@ -39,6 +46,37 @@ where
         a.rank()
     }
 
+    fn span_and_rank(&self, subset: &Set) -> (Set, usize) {
+        // row reduce with the columns of the subset first, then the first r rows have their
+        // pivots in the subset, and the other rows are zero in the columns of the subset
+        let inside: Vec<usize> = subset.into();
+        let outside: Vec<usize> = (0..self.n())
+            .filter(|e| !subset.contains_element(*e))
+            .collect();
+
+        let mut a = DynMatrix::new(self.matrix.num_rows(), self.n());
+        for i in 0..self.matrix.num_rows() {
+            for (j, &c) in inside.iter().chain(&outside).enumerate() {
+                a[(i, j)] = self.matrix[(i, c)].clone();
+            }
+        }
+        a.gauss_jordan();
+
+        let zero = E::from(0u8);
+        let r = (0..a.num_rows())
+            .take_while(|&i| (0..inside.len()).any(|j| a[(i, j)] != zero))
+            .count();
+
+        // an element is in the span if its column is zero outside the first r rows
+        let span = outside
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| (r..a.num_rows()).all(|i| a[(i, inside.len() + j)] == zero))
+            .fold(*subset, |acc, (_, &e)| acc.add_element(e));
+
+        (span, r)
+    }
+
     fn k(&self) -> usize {
         self.rank
     }
@@ -48,9 +86,179 @@ where
     }
 }
 
+impl<E> MatrixMatroid<E>
+where
+    E: Clone
+        + Add<Output = E>
+        + Sub<Output = E>
+        + Mul<Output = E>
+        + Div<Output = E>
+        + Neg<Output = E>
+        + From<u8>
+        + PartialEq,
+{
+    /// checks if the matroid is representable over the finite field F, i.e. if there is a matrix
+    /// over F with the same column matroid.
+    ///
+    /// This is a brute force search through all the matrices in standard form that are consistent
+    /// with the circuits of the matroid, so it takes exponential time in the number of entries,
+    /// and is only feasible for small matroids over small fields.
+    pub fn is_representable_over<F: FiniteField>(&self) -> bool {
+        find_representation::<F, _>(self).is_some()
+    }
+
+    /// The minimum distance d of the linear code with the matrix as generator matrix.
+    ///
+    /// The codewords of minimal support correspond to the cocircuits of the matroid, so this is
+    /// the cogirth of the matroid (the girth of the dual matroid, which is the matroid of a parity
+    /// check matrix), and equal to the generalized hamming distance d_1.
+    /// Is None if the code is the zero code.
+    pub fn min_distance(&self) -> Option<usize> {
+        self.cogirth()
+    }
+
+    /// The parameters [n, k, d] of the linear code with the matrix as generator matrix.
+    /// These are the length, the dimension and the minimum distance of the code.
+    pub fn code_parameters(&self) -> (usize, usize, Option<usize>) {
+        (self.n(), self.k(), self.min_distance())
+    }
+}
+
+impl<F: FiniteField> MatrixMatroid<F> {
+    /// The matroid of a random rows x cols matrix over the finite field F, where every entry is
+    /// chosen uniformly from the elements of the field. The field is chosen by the type, e.g.
+    /// `MatrixMatroid::<GF3>::random(3, 6, &mut rng)` for a matrix over GF(3).
+    ///
+    /// There is no argument for the field size q: the entries are elements of F, so q is fixed by
+    /// the type, and a runtime q could not choose the type of the returned matroid. Use `Fp<P>`
+    /// for the prime field GF(P).
+    ///
+    /// Over large fields this is a generic representable matroid, which with high probability
+    /// is the uniform matroid U(min(rows, cols), cols).
+    pub fn random<R: Rng + ?Sized>(rows: usize, cols: usize, rng: &mut R) -> Self {
+        let elements = F::elements();
+        let mut matrix = DynMatrix::new(rows, cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                matrix[(i, j)] = *elements.choose(rng).unwrap();
+            }
+        }
+
+        MatrixMatroid::from(matrix)
+    }
+
+    /// The Hamming weight enumerator of the linear code with the matrix as generator matrix,
+    /// where element w is the number of codewords of weight w.
+    ///
+    /// The codewords that are zero on the coordinates X form a subspace of dimension k - r(X), so
+    /// there are q^(k - r(X)) of them, and the number of codewords that are zero exactly on a set
+    /// Z follows by inclusion-exclusion over the supersets of Z (Greene). So this takes the rank
+    /// of all 2^n subsets, instead of enumerating the q^k codewords.
+    pub fn weight_enumerator(&self) -> Vec<u64> {
+        let q = F::elements().len() as i64;
+        self.count_by_support(|dimension| q.pow(dimension as u32))
+    }
+
+    /// The support weight distribution of the linear code with the matrix as generator matrix,
+    /// where element w is the number of subcodes of dimension r whose support has w elements.
+    ///
+    /// For r = 1, this is the weight enumerator divided by q - 1 (except for w = 0), and the
+    /// smallest w with a non-zero count is the generalized Hamming distance d_r. It is found like
+    /// the weight enumerator, where a subspace of dimension l has [l choose r]_q subspaces of
+    /// dimension r.
+    pub fn support_weight_distribution(&self, r: usize) -> Vec<u64> {
+        let q = F::elements().len() as i64;
+        self.count_by_support(|dimension| gaussian_binomial(q, dimension, r))
+    }
+
+    /// Count subspaces of the code by the size of their support, where count(l) is the number of
+    /// the subspaces in a code of dimension l.
+    fn count_by_support(&self, count: impl Fn(usize) -> i64) -> Vec<u64> {
+        let n = self.n();
+        let mut counts = vec![0i64; n + 1];
+
+        for x in SetIterator::new(n) {
+            // the subspaces that are zero on x
+            let zero_on_x = count(self.k() - self.rank(&x));
+            for z in 0..=x.size() {
+                let sign = if (x.size() - z) % 2 == 0 { 1 } else { -1 };
+                counts[n - z] += sign * binomial(x.size(), z) as i64 * zero_on_x;
+            }
+        }
+
+        counts.into_iter().map(|c| c as u64).collect()
+    }
+}
+
+/// The number of subspaces of dimension r of a vector space of dimension l over the field with q
+/// elements.
+fn gaussian_binomial(q: i64, l: usize, r: usize) -> i64 {
+    if r > l {
+        return 0;
+    }
+
+    let (numerator, denominator) = (0..r).fold((1, 1), |(num, den), i| {
+        (
+            num * (q.pow((l - i) as u32) - 1),
+            den * (q.pow(i as u32 + 1) - 1),
+        )
+    });
+    numerator / denominator
+}
+
+impl MatrixMatroid<Rational<BigInt>> {
+    /// The matroid of a real point configuration, where each column is a vector.
+    ///
+    /// The row reduction compares the entries exactly with zero, so a matrix over f64 would get
+    /// the rank wrong whenever a rounding error is left behind. Every float is therefore
+    /// converted to the rational number it represents, and the matroid is calculated exactly.
+    /// Is None if the columns have different lengths, or if any entry is infinite or NaN.
+    pub fn from_real_columns(columns: &[&[f64]]) -> Option<Self> {
+        let columns = columns
+            .iter()
+            .map(|column| column.iter().map(|&x| Rational::from_f64(x)).collect())
+            .collect::<Option<Vec<Vec<_>>>>()?;
+        let columns: Vec<&[Rational<BigInt>]> = columns.iter().map(|c| c.as_slice()).collect();
+
+        DynMatrix::from_columns(&columns).map(MatrixMatroid::from)
+    }
+
+    /// The matroid of the central hyperplane arrangement over Q with the given normal vectors,
+    /// where element i is the hyperplane {x : a_i · x = 0} with normal a_i.
+    ///
+    /// A set of hyperplanes is independent if the normals are linearly independent, so the flats
+    /// of the matroid correspond to the intersections of the hyperplanes, and e.g. the number of
+    /// regions of a real arrangement is T(2, 0) (Zaslavsky).
+    /// Is None if there are no hyperplanes, or if the normals have different lengths.
+    pub fn from_hyperplane_arrangement(normals: &[&[Rational<BigInt>]]) -> Option<Self> {
+        if normals.is_empty() {
+            return None;
+        }
+
+        DynMatrix::from_columns(normals).map(MatrixMatroid::from)
+    }
+
+    /// The algebraic matroid of the monomial map t -> (t^a_1, ..., t^a_n), where the monomial
+    /// t^a_i is given by its exponent vector a_i (negative exponents are allowed).
+    ///
+    /// Monomials are algebraically independent over Q exactly when their exponent vectors are
+    /// linearly independent, so this is the matroid of the exponent vectors over Q, e.g. the
+    /// constant monomial is a loop and t^2 and t^3 are parallel.
+    /// Is None if there are no monomials, or if the exponent vectors have different lengths.
+    pub fn from_monomials(exponents: &[&[i64]]) -> Option<Self> {
+        let columns: Vec<Vec<Rational<BigInt>>> = exponents
+            .iter()
+            .map(|a| a.iter().map(|&e| Rational::from(BigInt::from(e))).collect())
+            .collect();
+        let columns: Vec<&[Rational<BigInt>]> = columns.iter().map(|c| c.as_slice()).collect();
+
+        Self::from_hyperplane_arrangement(&columns)
+    }
+}
+
 impl<E> From<DynMatrix<E>> for MatrixMatroid<E>
 where
-    E: Copy
+    E: Clone
         + Add<Output = E>
         + Sub<Output = E>
         + Mul<Output = E>
@@ -72,7 +280,9 @@ where
 mod tests {
     use super::*;
 
-    use tinyfield::prime_field::PrimeField;
+    use crate::field::{GF3, GF4};
+
+    use tinyfield::prime_field::{PrimeField, PrimeFieldElt};
     use tinyfield::GF2;
 
     #[test]
@@ -86,4 +296,247 @@ mod tests {
         assert!(matroid.rank(&[0usize, 3].into()) == 1);
         assert!(matroid.rank(&[0usize, 1].into()) == 2);
     }
+
+    #[test]
+    fn hamming_code_parameters() {
+        let one = GF2::one;
+        let zer = GF2::zero;
+        let g = DynMatrix::from_rows(&[
+            &[one, zer, zer, zer, zer, one, one],
+            &[zer, one, zer, zer, one, zer, one],
+            &[zer, zer, one, zer, one, one, zer],
+            &[zer, zer, zer, one, one, one, one],
+        ])
+        .unwrap();
+
+        let matroid = MatrixMatroid::from(g);
+
+        assert_eq!(matroid.code_parameters(), (7, 4, Some(3)));
+        assert_eq!(
+            matroid.min_distance(),
+            matroid.generalized_hamming_distance(1)
+        );
+        // the dual code is the simplex code, with minimum distance 4
+        assert_eq!(matroid.girth(), Some(4));
+
+        assert_eq!(matroid.weight_enumerator(), vec![1, 0, 0, 7, 7, 0, 0, 1]);
+
+        // only the zero code has dimension 0, and the code itself dimension 4
+        assert_eq!(
+            matroid.support_weight_distribution(0),
+            vec![1, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            matroid.support_weight_distribution(1),
+            vec![0, 0, 0, 7, 7, 0, 0, 1]
+        );
+        assert_eq!(
+            matroid.support_weight_distribution(4),
+            vec![0, 0, 0, 0, 0, 0, 0, 1]
+        );
+        assert_eq!(matroid.support_weight_distribution(5), vec![0; 8]);
+
+        // there are 35 subcodes of dimension 2, and the smallest support is d_2
+        let distribution = matroid.support_weight_distribution(2);
+        assert_eq!(distribution.iter().sum::<u64>(), 35);
+        assert_eq!(
+            distribution.iter().position(|&c| c > 0),
+            matroid.generalized_hamming_distance(2)
+        );
+    }
+
+    #[test]
+    fn weight_enumerator() {
+        let golay = crate::matroid::examples::ternary_golay();
+        let mut expected = vec![0; 13];
+        expected[0] = 1;
+        expected[6] = 264;
+        expected[9] = 440;
+        expected[12] = 24;
+        assert_eq!(golay.weight_enumerator(), expected);
+
+        // the repetition code
+        let one = GF3::from(1);
+        let matroid = MatrixMatroid::from(DynMatrix::from_rows(&[&[one, one, one]]).unwrap());
+        assert_eq!(matroid.weight_enumerator(), vec![1, 0, 0, 2]);
+    }
+
+    #[test]
+    fn rational() {
+        let rational = |n: i32, d: i32| Rational::from(BigInt::from(n)) / Rational::from(d.into());
+        let one = rational(1, 1);
+        let zero = rational(0, 1);
+        // three distinct directions in the plane give U(2, 3)
+        let a = DynMatrix::from_rows(&[
+            &[one.clone(), zero.clone(), rational(1, 2)],
+            &[zero, one, rational(-2, 3)],
+        ])
+        .unwrap();
+
+        let matroid = MatrixMatroid::from(a);
+        assert!(matroid.is_equal(&crate::matroid::UniformMatroid::new(2, 3)));
+        // the only circuit is the whole ground set, and every pair is a basis
+        assert_eq!(matroid.circuits(), vec![Set::from(0b111)]);
+        assert_eq!(matroid.bases().len(), 3);
+    }
+
+    #[test]
+    fn real_points() {
+        // points in the plane, with a one appended to get the affine dependencies. The first
+        // three are on the line y = x / 2, while 0.1 + 0.2 != 0.3 as floats, so the last three
+        // points are not exactly on the line y = x
+        let matroid = MatrixMatroid::from_real_columns(&[
+            &[0.5, 0.25, 1.0],
+            &[1.0, 0.5, 1.0],
+            &[1.5, 0.75, 1.0],
+            &[0.1, 0.1, 1.0],
+            &[0.2, 0.2, 1.0],
+            &[0.1 + 0.2, 0.3, 1.0],
+        ])
+        .unwrap();
+
+        assert_eq!(matroid.k(), 3);
+        assert!(matroid.is_circuit(&Set::from(0b000111)));
+        assert!(matroid.is_independent(&Set::from(0b111000)));
+        assert_eq!(matroid.rank(&Set::from(0b011000)), 2);
+
+        assert!(MatrixMatroid::from_real_columns(&[&[1.0, 0.0], &[0.0]]).is_none());
+        assert!(MatrixMatroid::from_real_columns(&[&[f64::NAN]]).is_none());
+    }
+
+    #[test]
+    fn hyperplane_arrangement() {
+        let q = |numerator: i32, denominator: i32| {
+            Rational::new(BigInt::from(numerator), BigInt::from(denominator)).unwrap()
+        };
+
+        // the braid arrangement x_i = x_j in Q^4, whose matroid is the cycle matroid of K_4
+        let pairs = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+        let normals: Vec<Vec<Rational<BigInt>>> = pairs
+            .iter()
+            .map(|&(i, j)| {
+                (0..4)
+                    .map(|l| match l {
+                        l if l == i => q(1, 1),
+                        l if l == j => q(-1, 1),
+                        _ => q(0, 1),
+                    })
+                    .collect()
+            })
+            .collect();
+        let normals: Vec<&[Rational<BigInt>]> = normals.iter().map(|n| n.as_slice()).collect();
+        let braid = MatrixMatroid::from_hyperplane_arrangement(&normals).unwrap();
+
+        assert_eq!(braid.k(), 3);
+        // the triangles of K_4
+        assert!(braid.is_circuit(&Set::from(0b001011)));
+        // the arrangement has 4! regions, one for each ordering of the coordinates
+        let tutte = braid.tutte_polynomial();
+        let regions: i64 = (0..tutte.len()).map(|i| tutte[i][0] << i).sum();
+        assert_eq!(regions, 24);
+
+        // the lines through the origin with slopes 1/2, 1/3 and 1/6 are distinct, but three
+        // lines in the plane are dependent
+        let lines = MatrixMatroid::from_hyperplane_arrangement(&[
+            &[q(1, 2), q(-1, 1)],
+            &[q(1, 3), q(-1, 1)],
+            &[q(1, 6), q(-1, 1)],
+        ])
+        .unwrap();
+        assert!(lines.is_equal(&crate::matroid::UniformMatroid::new(2, 3)));
+
+        assert!(MatrixMatroid::from_hyperplane_arrangement(&[]).is_none());
+        assert!(Rational::new(BigInt::from(1), BigInt::from(0)).is_none());
+    }
+
+    #[test]
+    fn closure() {
+        use crate::matroid::BasesMatroid;
+        use crate::set::SetIterator;
+
+        let one = GF2::one;
+        let zero = GF2::zero;
+        let matrix = DynMatrix::from_rows(&[
+            &[one, zero, one, one, zero, zero],
+            &[zero, one, one, one, zero, zero],
+            &[zero, zero, zero, zero, zero, one],
+        ])
+        .unwrap();
+        let matroid = MatrixMatroid::from(matrix);
+        let general = BasesMatroid::new(matroid.bases(), 6, 3);
+
+        // the elements 2 and 3 are parallel, and 4 is a loop
+        assert_eq!(matroid.closure(&Set::from(0b000100)), Set::from(0b011100));
+        for s in SetIterator::new(6) {
+            assert_eq!(matroid.span_and_rank(&s), general.span_and_rank(&s));
+        }
+
+        // over GF(3) the pivots are not all one, so the row reduction has to divide
+        let one = GF3::from(1);
+        let two = GF3::from(2);
+        let zero = GF3::from(0);
+        let matrix = DynMatrix::from_rows(&[
+            &[two, zero, two, one, one, zero],
+            &[zero, two, two, two, one, zero],
+            &[one, one, zero, zero, two, two],
+        ])
+        .unwrap();
+        let matroid = MatrixMatroid::from(matrix);
+        let general = BasesMatroid::new(matroid.bases(), 6, 3);
+        for s in SetIterator::new(6) {
+            assert_eq!(matroid.span_and_rank(&s), general.span_and_rank(&s));
+        }
+    }
+
+    #[test]
+    fn monomials() {
+        // the monomials x, y, xy and x^2 y^-1 in two variables
+        let matroid =
+            MatrixMatroid::from_monomials(&[&[1, 0], &[0, 1], &[1, 1], &[2, -1]]).unwrap();
+        assert!(matroid.is_equal(&crate::matroid::UniformMatroid::new(2, 4)));
+
+        // the constant monomial is a loop, and powers of the same monomial are parallel
+        let matroid = MatrixMatroid::from_monomials(&[&[0, 0], &[2, 0], &[3, 0], &[1, 1]]).unwrap();
+        assert_eq!(matroid.rank(&Set::from(0b0001)), 0);
+        assert!(matroid.is_circuit(&Set::from(0b0110)));
+        assert_eq!(matroid.k(), 2);
+
+        assert!(MatrixMatroid::from_monomials(&[&[1, 0], &[1]]).is_none());
+    }
+
+    #[test]
+    fn random() {
+        use crate::field::Fp;
+
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(5);
+
+        let binary = MatrixMatroid::<PrimeFieldElt<GF2>>::random(3, 7, &mut rng);
+        assert_eq!(binary.n(), 7);
+        assert!(binary.k() <= 3);
+        assert_eq!(binary.check_rank_axioms(), Ok(()));
+        assert!(binary.is_representable_over::<PrimeFieldElt<GF2>>());
+
+        // over a large field, the matroid is almost surely uniform
+        let generic = MatrixMatroid::<Fp<251>>::random(3, 6, &mut rng);
+        assert!(generic.is_equal(&crate::matroid::UniformMatroid::new(3, 6)));
+    }
+
+    #[test]
+    fn representable_over() {
+        let one = GF3::from(1);
+        let two = GF3::from(2);
+        let zero = GF3::from(0);
+        // U(2, 4) over GF(3)
+        let a = DynMatrix::from_rows(&[&[one, zero, one, one], &[zero, one, one, two]]).unwrap();
+
+        let matroid = MatrixMatroid::from(a);
+
+        assert!(matroid.is_uniform());
+        assert!(matroid.is_representable_over::<GF3>());
+        assert!(matroid.is_representable_over::<GF4>());
+        assert!(!matroid.is_representable_over::<PrimeFieldElt<GF2>>());
+    }
 }
@@ -1,13 +1,24 @@
+use std::collections::{BTreeMap, HashMap};
 use std::error::Error;
+use std::io::{Read, Write};
 use std::path::Path;
 
 use num_integer::binomial;
-use rayon::prelude::*;
-
-use super::storage::StoredMatroid;
-use super::{BasesMatroid, CombinatorialDerived, Dual, Elongate};
+use tinyfield::prime_field::PrimeFieldElt;
+use tinyfield::GF2;
+
+use super::representation::find_graph;
+use super::storage::{
+    LoadedMatroid, StorageError, StorageFormat, StoredMatroid, StoredMatroidKind,
+};
+use super::verify::Violation;
+use super::{
+    BasesMatroid, CombinatorialDerived, CombinatorialDerivedIter, Dual, Elongate, FreeCoextension,
+    HiggsFamily, Minor, PrincipalExtension,
+};
 
 use crate::betti_nums::BettiNumbers;
+use crate::parallel::*;
 use crate::set::{Set, SetIterator};
 
 /// A matroid
@@ -56,6 +67,89 @@ pub trait Matroid {
         None
     }
 
+    /// The weight hierarchy (d_1, d_2, ..., d_k) of the matroid, i.e. all the generalized hamming
+    /// distances.
+    ///
+    /// The corank of every subset is calculated once, instead of once for every h as when calling
+    /// generalized_hamming_distance for each h.
+    fn weight_hierarchy(&self) -> Vec<usize> {
+        let n = self.n();
+        let k = self.k();
+
+        // corank(X) <= |X| - h is the same as h <= |X| - corank(X) = k - r(E - X), so smallest[m]
+        // is the size of the smallest subset with |X| - corank(X) = m
+        let mut smallest = vec![usize::MAX; k + 1];
+        for subset in SetIterator::new(n) {
+            let m = subset.size() - self.corank(&subset);
+            smallest[m] = smallest[m].min(subset.size());
+        }
+
+        // d_h is the smallest subset with |X| - corank(X) >= h
+        let mut hierarchy = vec![0; k];
+        let mut best = usize::MAX;
+        for h in (1..=k).rev() {
+            best = best.min(smallest[h]);
+            hierarchy[h - 1] = best;
+        }
+        hierarchy
+    }
+
+    /// The Singleton defect n - k + h - d_h of the generalized hamming distance d_h, which is at
+    /// most n - k + h by the generalized Singleton bound. Is None unless 1 <= h <= k.
+    fn singleton_defect(&self, h: usize) -> Option<usize> {
+        if h == 0 || h > self.k() {
+            return None;
+        }
+
+        let d = self.generalized_hamming_distance(h)?;
+        Some(self.n() - self.k() + h - d)
+    }
+
+    /// checks if the code of the matroid is MDS (maximum distance separable), i.e. if d_1 meets
+    /// the Singleton bound n - k + 1. Then every d_h meets its bound, and the matroid is uniform.
+    /// The zero code (k = 0) is MDS.
+    fn is_mds(&self) -> bool {
+        self.k() == 0 || self.singleton_defect(1) == Some(0)
+    }
+
+    /// Checks Wei duality: the weight hierarchy d_1 < ... < d_k of the matroid, and the values
+    /// n + 1 - d_h of the weight hierarchy of the dual, are together exactly the numbers 1..=n.
+    ///
+    /// This holds for every matroid, so a violation means that the rank function is not the
+    /// rank function of a matroid. Returns the merged hierarchy, the hierarchy of the matroid
+    /// followed by the transformed hierarchy of the dual, or the first number that is missing or
+    /// repeated.
+    fn verify_wei_duality(&self) -> Result<Vec<usize>, Violation>
+    where
+        Self: Sized,
+    {
+        let n = self.n();
+        let hierarchy = self.weight_hierarchy();
+        let dual_hierarchy = self.dual().weight_hierarchy();
+
+        let mut merged = hierarchy.clone();
+        for (i, &d) in dual_hierarchy.iter().enumerate() {
+            if d > n {
+                return Err(Violation::DualHammingDistance { h: i + 1, d, n });
+            }
+            merged.push(n + 1 - d);
+        }
+
+        for w in 1..=n {
+            match merged.iter().filter(|&&d| d == w).count() {
+                1 => {}
+                0 => return Err(Violation::WeiDualityMissing { missing: w, merged }),
+                _ => {
+                    return Err(Violation::WeiDualityRepeated {
+                        repeated: w,
+                        merged,
+                    })
+                }
+            }
+        }
+        Ok(merged)
+    }
+
     /// the corank of the specific subset
     fn corank(&self, subset: &Set) -> usize {
         subset.size() + self.rank(&Set::of_size(self.n()).difference(subset)) - self.k()
@@ -81,11 +175,326 @@ pub trait Matroid {
         subset.size() - self.rank(subset) == 1 && self.is_cycle(subset)
     }
 
+    /// checks if a subset is a cocircuit (a circuit of the dual matroid), i.e. a minimal set
+    /// whose complement is not spanning.
+    fn is_cocircuit(&self, subset: &Set) -> bool {
+        let ground_set = Set::of_size(self.n());
+        let k = self.k();
+
+        !subset.is_empty()
+            && self.rank(&ground_set.difference(subset)) < k
+            && subset
+                .bits()
+                .all(|e| self.rank(&ground_set.difference(&subset.remove_element(e))) == k)
+    }
+
+    /// Returns a list of all cocircuits of the matroid
+    /// They are found with the rank function of self, so neither the dual nor its bases are
+    /// constructed.
+    fn cocircuits(&self) -> Vec<Set> {
+        SetIterator::new(self.n())
+            .size_limit(self.n() - self.k() + 1)
+            .smaller_equal()
+            .filter(|set| self.is_cocircuit(set))
+            .collect()
+    }
+
+    /// The loops of the matroid, the elements of rank 0
+    fn loops(&self) -> Set {
+        (0..self.n())
+            .filter(|&e| self.rank(&Set::empty().add_element(e)) == 0)
+            .fold(Set::empty(), |acc, e| acc.add_element(e))
+    }
+
+    /// The coloops of the matroid, the elements in every basis, i.e. the elements whose removal
+    /// lowers the rank of the ground set
+    fn coloops(&self) -> Set {
+        let ground_set = Set::of_size(self.n());
+        (0..self.n())
+            .filter(|&e| self.rank(&ground_set.remove_element(e)) < self.k())
+            .fold(Set::empty(), |acc, e| acc.add_element(e))
+    }
+
+    /// Returns a list of all cocircuits of the matroid, but calculated in parallel
+    fn par_cocircuits(&self) -> Vec<Set>
+    where
+        Self: Sync,
+    {
+        let mut cocircuits = Vec::new();
+        for cardinality in 1..=(self.n() - self.k() + 1) {
+            let cocircuits_of_cardinality: Vec<Set> = SetIterator::new(self.n())
+                .size_limit(cardinality)
+                .equal()
+                .par_bridge()
+                .filter(|set| self.is_cocircuit(set))
+                .collect();
+            cocircuits.extend(cocircuits_of_cardinality);
+        }
+        cocircuits
+    }
+
     /// checks if a subset is independent
     fn is_independent(&self, subset: &Set) -> bool {
         self.rank(subset) == subset.size()
     }
 
+    /// The closure (span) of the subset together with its rank.
+    /// The rank of the subset is only calculated once.
+    fn span_and_rank(&self, subset: &Set) -> (Set, usize) {
+        let r = self.rank(subset);
+
+        // the closure is every element that does not increase the rank of the subset
+        let span = (0..self.n())
+            .filter(|e| !subset.contains_element(*e))
+            .filter(|e| self.rank(&subset.add_element(*e)) == r)
+            .fold(*subset, |acc, e| acc.add_element(e));
+
+        (span, r)
+    }
+
+    /// The closure (span) of the subset, every element that does not increase the rank.
+    /// This uses span_and_rank, which is specialized for the matroids with a faster way to find
+    /// the span than trying every element.
+    fn closure(&self, subset: &Set) -> Set {
+        self.span_and_rank(subset).0
+    }
+
+    /// checks if the subset is a flat (is equal to its closure)
+    fn is_flat(&self, subset: &Set) -> bool {
+        self.closure(subset) == *subset
+    }
+
+    /// The cyclic flats (flats that are unions of circuits) with their ranks.
+    /// A flat is cyclic if removing any one of its elements does not lower the rank.
+    fn cyclic_flats(&self) -> Vec<(Set, usize)> {
+        SetIterator::new(self.n())
+            .filter_map(|s| {
+                let (span, rank) = self.span_and_rank(&s);
+                let cyclic = s.bits().all(|e| self.rank(&s.remove_element(e)) == rank);
+                (span == s && cyclic).then_some((s, rank))
+            })
+            .collect()
+    }
+
+    /// Returns a list of all the flats of the given rank
+    fn flats_of_rank(&self, rank: usize) -> Vec<Set> {
+        SetIterator::new(self.n())
+            .filter(|s| self.rank(s) == rank && self.is_flat(s))
+            .collect()
+    }
+
+    /// checks if the subset is a modular flat, i.e. a flat F with r(F) + r(G) = r(F ∪ G) +
+    /// r(F ∩ G) for every flat G. Modular flats are the ones along which generalized parallel
+    /// connections can be taken.
+    fn is_modular_flat(&self, subset: &Set) -> bool {
+        let rank = self.rank(subset);
+        self.is_flat(subset)
+            && (0..=self.k())
+                .flat_map(|r| self.flats_of_rank(r).into_iter().map(move |g| (g, r)))
+                .all(|(g, r)| {
+                    rank + r == self.rank(&subset.union(&g)) + self.rank(&subset.intersect(&g))
+                })
+    }
+
+    /// The modular flats of the matroid, see [`Matroid::is_modular_flat`].
+    fn modular_flats(&self) -> Vec<Set> {
+        let flats: Vec<(Set, usize)> = (0..=self.k())
+            .flat_map(|r| self.flats_of_rank(r).into_iter().map(move |f| (f, r)))
+            .collect();
+
+        flats
+            .iter()
+            .filter(|(f, rf)| {
+                flats
+                    .iter()
+                    .all(|(g, rg)| rf + rg == self.rank(&f.union(g)) + self.rank(&f.intersect(g)))
+            })
+            .map(|(f, _)| *f)
+            .collect()
+    }
+
+    /// The modular pairs of flats, the pairs (F, G) with r(F) + r(G) = r(F ∪ G) + r(F ∩ G).
+    /// Two flats where one contains the other always form a modular pair, so only the pairs
+    /// of incomparable flats are listed, each pair once.
+    fn modular_pairs(&self) -> Vec<(Set, Set)> {
+        let flats: Vec<(Set, usize)> = (0..=self.k())
+            .flat_map(|r| self.flats_of_rank(r).into_iter().map(move |f| (f, r)))
+            .collect();
+
+        flats
+            .iter()
+            .enumerate()
+            .flat_map(|(i, (f, rf))| {
+                flats[i + 1..]
+                    .iter()
+                    .filter(move |(g, rg)| {
+                        !(f <= g || g <= f)
+                            && rf + rg == self.rank(&f.union(g)) + self.rank(&f.intersect(g))
+                    })
+                    .map(move |(g, _)| (*f, *g))
+            })
+            .collect()
+    }
+
+    /// The number of flats of each rank (the sizes of the ranks in the lattice of flats).
+    /// These are the Whitney numbers of the second kind, and element i is the number of flats of
+    /// rank i.
+    fn lattice_rank_sizes(&self) -> Vec<usize> {
+        let mut sizes = vec![0; self.k() + 1];
+        for s in SetIterator::new(self.n()) {
+            let (span, rank) = self.span_and_rank(&s);
+            if span == s {
+                sizes[rank] += 1;
+            }
+        }
+        sizes
+    }
+
+    /// The girth of the matroid, the cardinality of the smallest circuit.
+    /// Is None if the matroid has no circuits.
+    fn girth(&self) -> Option<usize> {
+        // the smallest dependent set is a circuit
+        (1..=self.n()).find(|&i| {
+            SetIterator::new(self.n())
+                .size_limit(i)
+                .equal()
+                .any(|s| !self.is_independent(&s))
+        })
+    }
+
+    /// The cogirth of the matroid, the cardinality of the smallest cocircuit (circuit of the dual).
+    /// Is None if the matroid has no cocircuits.
+    fn cogirth(&self) -> Option<usize> {
+        let ground_set = Set::of_size(self.n());
+        // the smallest set whose complement is not spanning is a cocircuit
+        (1..=self.n()).find(|&i| {
+            SetIterator::new(self.n())
+                .size_limit(i)
+                .equal()
+                .any(|s| self.rank(&ground_set.difference(&s)) < self.k())
+        })
+    }
+
+    /// The circumference of the matroid, the cardinality of the largest circuit.
+    /// Is None if the matroid has no circuits.
+    ///
+    /// A circuit has at most r(M) + 1 elements, so the sizes are tried from there and down, and
+    /// the search stops at the first size with a circuit.
+    fn circumference(&self) -> Option<usize> {
+        (1..=(self.k() + 1).min(self.n())).rev().find(|&i| {
+            SetIterator::new(self.n())
+                .size_limit(i)
+                .equal()
+                .any(|s| self.is_circuit(&s))
+        })
+    }
+
+    /// The cocircumference of the matroid, the cardinality of the largest cocircuit.
+    /// Is None if the matroid has no cocircuits.
+    ///
+    /// Like [`Matroid::circumference`], from the largest possible size n - r(M) + 1 and down.
+    fn cocircumference(&self) -> Option<usize> {
+        (1..=(self.n() - self.k() + 1).min(self.n()))
+            .rev()
+            .find(|&i| {
+                SetIterator::new(self.n())
+                    .size_limit(i)
+                    .equal()
+                    .any(|s| self.is_cocircuit(&s))
+            })
+    }
+
+    /// checks if the matroid is connected, i.e. if every pair of elements is contained in a
+    /// common circuit.
+    fn is_connected(&self) -> bool {
+        self.n() <= 1 || self.connected_components().len() == 1
+    }
+
+    /// The connected components of the matroid, the classes of the relation where two elements
+    /// are related if they are in a common circuit. Loops and coloops are components of their own.
+    /// The components are ordered by their smallest element.
+    ///
+    /// The fundamental circuits with respect to any basis give the same classes as all the
+    /// circuits, so only O(nk) rank queries are needed instead of enumerating the circuits.
+    fn connected_components(&self) -> Vec<Set> {
+        components_by_circuits(self.n(), &fundamental_circuits(self))
+    }
+
+    /// The connectivity function λ(X) = r(X) + r(E - X) - r(M) of the subset.
+    /// It is symmetric in X and its complement, and equal for the matroid and its dual.
+    fn connectivity(&self, subset: &Set) -> usize {
+        let complement = Set::of_size(self.n()).difference(subset);
+        self.rank(subset) + self.rank(&complement) - self.k()
+    }
+
+    /// checks if the matroid is (Tutte) k-connected, i.e. if there is no l-separation for l < k.
+    /// An l-separation is a partition (X, Y) of the ground set with |X|, |Y| >= l and
+    /// λ(X) < l. Every matroid is 1-connected, and 2-connected is the same as connected.
+    ///
+    /// Every subset is checked, so this is only feasible for small matroids.
+    fn is_k_connected(&self, k: usize) -> bool {
+        let n = self.n();
+        // X has an (λ(X) + 1)-separation if both sides are big enough
+        SetIterator::new(n).all(|x| {
+            let smallest_side = x.size().min(n - x.size());
+            let l = self.connectivity(&x) + 1;
+            l >= k || l > smallest_side
+        })
+    }
+
+    /// The vertical connectivity of the matroid, the smallest l such that there is a vertical
+    /// l-separation, a partition (X, Y) with λ(X) < l and r(X), r(Y) >= l. It is the rank of the
+    /// matroid if there is no vertical separation. For a graph with at least one edge between
+    /// each pair of adjacent vertices, this is the vertex connectivity of the graph.
+    fn vertical_connectivity(&self) -> usize {
+        let ground_set = Set::of_size(self.n());
+        SetIterator::new(self.n())
+            .filter_map(|x| {
+                let l = self.connectivity(&x) + 1;
+                let vertical = self.rank(&x) >= l && self.rank(&ground_set.difference(&x)) >= l;
+                vertical.then_some(l)
+            })
+            .fold(self.k(), usize::min)
+    }
+
+    /// checks if the matroid is 3-connected.
+    /// If it is not, an l-separation (X, Y) with l = 1 or l = 2 is returned as the witness, i.e.
+    /// a partition with |X|, |Y| >= l and λ(X) < l.
+    fn three_connected(&self) -> Result<(), (Set, Set)> {
+        let n = self.n();
+        let ground_set = Set::of_size(n);
+        SetIterator::new(n)
+            .find(|x| {
+                let l = self.connectivity(x) + 1;
+                l < 3 && l <= x.size().min(n - x.size())
+            })
+            .map_or(Ok(()), |x| Err((x, ground_set.difference(&x))))
+    }
+
+    /// The connected components as standalone matroids, i.e. the restriction to each of the
+    /// components in [`Matroid::connected_components`].
+    /// The elements of each component keep their order, but are re-indexed to 0, 1, ..., so the
+    /// direct sum of the components is isomorphic to self.
+    fn components(&self) -> Vec<BasesMatroid> {
+        self.connected_components()
+            .iter()
+            .map(|component| self.restrict(component))
+            .collect()
+    }
+
+    /// The girth, the cogirth and whether the matroid is connected.
+    /// The circuits are only calculated once, and used both for the girth and the connectivity.
+    fn connectivity_parameters(&self) -> (Option<usize>, Option<usize>, bool) {
+        let circuits = self.circuits();
+        let girth = circuits.iter().map(|c| c.size()).min();
+
+        (
+            girth,
+            self.cogirth(),
+            connected_by_circuits(self.n(), &circuits),
+        )
+    }
+
     /// Returns a list of all circuits of the matroid
     fn circuits(&self) -> Vec<Set> {
         SetIterator::new(self.n())
@@ -95,6 +504,22 @@ pub trait Matroid {
             .collect()
     }
 
+    /// The circuits of the deletion M \ e, found from the circuits of self instead of a new
+    /// enumeration: they are the circuits not containing e. The elements after e are shifted down
+    /// by one, like in delete_contract and minor.
+    fn circuits_after_deleting(&self, e: usize) -> Vec<Set> {
+        self.circuits()
+            .into_iter()
+            .filter(|c| !c.contains_element(e))
+            .map(|c| {
+                c.bits()
+                    .map(|x| if x > e { x - 1 } else { x })
+                    .collect::<Vec<usize>>()
+                    .into()
+            })
+            .collect()
+    }
+
     /// Returns a list of all circuits of the matroid, but calculated in parallel
     fn par_circuits(&self) -> Vec<Set>
     where
@@ -123,6 +548,8 @@ pub trait Matroid {
     }
 
     /// Returns a list of all bases of the matroid
+    /// There is no guarantee on the order of the bases, it may differ between implementations
+    /// and between runs. Use [`Matroid::sorted_bases`] if the order matters.
     fn bases(&self) -> Vec<Set> {
         // every base is an independent set of size k
         SetIterator::new(self.n())
@@ -132,16 +559,167 @@ pub trait Matroid {
             .collect()
     }
 
+    /// The same matroid given by its bases, e.g. to put matroids of different types in one list
+    fn to_bases_matroid(&self) -> BasesMatroid {
+        BasesMatroid::new(self.bases(), self.n(), self.k())
+    }
+
+    /// Returns a list of all bases of the matroid, sorted ascending by their usize encoding
+    fn sorted_bases(&self) -> Vec<Set> {
+        let mut bases = self.bases();
+        bases.sort_by_key(|s| usize::from(*s));
+        bases
+    }
+
     /// the number of bases each element in the ground set is contained in (sorted)
     fn bases_series(&self) -> Vec<usize> {
+        let mut containment = self.element_basis_counts();
+        containment.sort();
+        containment
+    }
+
+    /// the number of bases each element in the ground set is contained in, where element e of the
+    /// result is the count for the element e.
+    fn element_basis_counts(&self) -> Vec<usize> {
         let bases = self.bases();
-        let mut containment = SetIterator::new(self.n())
+        SetIterator::new(self.n())
             .size_limit(1)
             .equal()
             .map(|element| bases.iter().filter(|&base| &element <= base).count())
-            .collect::<Vec<usize>>();
-        containment.sort();
-        containment
+            .collect()
+    }
+
+    /// the number of bases that contains every element of the subset
+    fn bases_containing(&self, subset: &Set) -> usize {
+        self.bases().iter().filter(|base| subset <= *base).count()
+    }
+
+    /// the number of bases that contains none of the elements of the subset
+    fn bases_avoiding(&self, subset: &Set) -> usize {
+        self.bases()
+            .iter()
+            .filter(|base| base.intersect(subset).is_empty())
+            .count()
+    }
+
+    /// A basis of largest total weight, where weights[e] is the weight of the element e.
+    ///
+    /// This is the greedy algorithm: the elements are visited from the heaviest, and added when
+    /// they keep the set independent. The matroids are exactly the structures where this always
+    /// finds an optimal basis (Rado, Edmonds). It takes n rank evaluations.
+    fn max_weight_basis(&self, weights: &[f64]) -> Set {
+        let mut order: Vec<usize> = (0..self.n()).collect();
+        order.sort_by(|&a, &b| weights[b].total_cmp(&weights[a]));
+
+        order.into_iter().fold(Set::empty(), |basis, e| {
+            let extended = basis.add_element(e);
+            if basis.size() < self.k() && self.is_independent(&extended) {
+                extended
+            } else {
+                basis
+            }
+        })
+    }
+
+    /// A basis of smallest total weight, where weights[e] is the weight of the element e.
+    /// See [`Matroid::max_weight_basis`].
+    fn min_weight_basis(&self, weights: &[f64]) -> Set {
+        let negated: Vec<f64> = weights.iter().map(|w| -w).collect();
+        self.max_weight_basis(&negated)
+    }
+
+    /// The largest number of disjoint bases, or None if the rank is 0 (when every basis is empty).
+    ///
+    /// There are m disjoint bases exactly when the union of m copies of the matroid has rank
+    /// m r(M) (Tutte and Nash-Williams). The largest union of m disjoint independent sets is
+    /// found by the matroid partition algorithm, see extend_partition, and one more set is added
+    /// until the sets are no longer all bases. Each set reuses the sets found before it.
+    fn base_packing_number(&self) -> Option<usize> {
+        let k = self.k();
+        if k == 0 {
+            return None;
+        }
+
+        let mut sets = Vec::new();
+        loop {
+            sets.push(Set::empty());
+            if extend_partition(self, &mut sets).size() < sets.len() * k {
+                return Some(sets.len() - 1);
+            }
+        }
+    }
+
+    /// The smallest number of independent sets (or bases) covering the ground set, or None if
+    /// there is a loop.
+    ///
+    /// The ground set is covered by m independent sets exactly when the union of m copies of the
+    /// matroid has rank n (Edmonds). As for [`Matroid::base_packing_number`], the matroid
+    /// partition algorithm is run with one more set until the sets cover the ground set.
+    fn base_covering_number(&self) -> Option<usize> {
+        if !self.loops().is_empty() {
+            return None;
+        }
+
+        let ground_set = Set::of_size(self.n());
+        let mut sets = Vec::new();
+        let mut union = Set::empty();
+        while union != ground_set {
+            sets.push(Set::empty());
+            union = extend_partition(self, &mut sets);
+        }
+
+        Some(sets.len())
+    }
+
+    /// The vertices and edges of the base polytope, the convex hull of the indicator vectors of
+    /// the bases.
+    ///
+    /// Vertex i is the indicator vector of the i-th basis of [`Matroid::sorted_bases`], and
+    /// (i, j) with i < j is an edge when the bases differ by a single exchange, i.e. share all
+    /// but one element. These are exactly the edges of the polytope (Gelfand, Goresky, MacPherson
+    /// and Serganova), so the edges form the basis exchange graph.
+    fn base_polytope(&self) -> (Vec<Vec<u8>>, Vec<(usize, usize)>) {
+        let bases = self.sorted_bases();
+        let vertices = bases
+            .iter()
+            .map(|base| {
+                (0..self.n())
+                    .map(|e| base.contains_element(e) as u8)
+                    .collect()
+            })
+            .collect();
+
+        let edges = bases
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| {
+                bases
+                    .iter()
+                    .enumerate()
+                    .skip(i + 1)
+                    .filter(move |(_, b)| a.intersect(b).size() + 1 == self.k())
+                    .map(move |(j, _)| (i, j))
+            })
+            .collect();
+
+        (vertices, edges)
+    }
+
+    /// The normalized volume of the base polytope, d! times its volume in the lattice of its
+    /// affine span, where d = n - (number of connected components) is its dimension.
+    ///
+    /// The polytope of a disconnected matroid is the product of the polytopes of the
+    /// components. For a connected matroid, the facets are x(S) = r(S) for the sets S where both
+    /// M|S and M/S are connected, with the polytope of M|S ⊕ M/S, so the volume is a sum over
+    /// the facets of the lattice distance from a vertex to the facet times the volume of the
+    /// facet. The arithmetic is exact, and this is feasible for matroids up to about n = 10.
+    fn base_polytope_volume(&self) -> u64 {
+        polytope_volume(
+            self,
+            Set::empty(),
+            Set::of_size(self.n()),
+            &mut HashMap::new(),
+        )
     }
 
     /// The fundamental circuit of the element e with respect to the basis
@@ -161,6 +739,15 @@ pub trait Matroid {
         Elongate::new(self, l)
     }
 
+    /// Iterator over the Higgs family of self, the truncations and elongations from the rank 0
+    /// matroid up to the free matroid. The i'th matroid has rank i, so self is the k'th.
+    fn higgs_family(&self) -> HiggsFamily<'_, Self>
+    where
+        Self: Sized,
+    {
+        HiggsFamily::new(self)
+    }
+
     /// Returns a new matroid that is the dual of self
     fn dual(&self) -> Dual<Self>
     where
@@ -169,7 +756,69 @@ pub trait Matroid {
         Dual::from(self)
     }
 
+    /// Returns a new matroid that is the principal extension of self by the flat.
+    /// The new element gets the index n.
+    fn principal_extension(&self, flat: &Set) -> PrincipalExtension<'_, Self>
+    where
+        Self: Sized,
+    {
+        PrincipalExtension::new(self, flat)
+    }
+
+    /// Returns a new matroid that is the free extension of self, where the new element is added
+    /// in general position, i.e. it is only in the span of the sets that span the matroid.
+    /// This is the principal extension by the ground set, and the new element gets the index n.
+    fn free_extension(&self) -> PrincipalExtension<'_, Self>
+    where
+        Self: Sized,
+    {
+        PrincipalExtension::new(self, &Set::of_size(self.n()))
+    }
+
+    /// Returns a new matroid that is the free coextension of self, the dual of the free
+    /// extension of the dual. The new element gets the index n, and the rank increases by one.
+    fn free_coextension(&self) -> FreeCoextension<'_, Self>
+    where
+        Self: Sized,
+    {
+        FreeCoextension::new(self)
+    }
+
+    /// Adds a new point freely on the line (a flat of rank 2).
+    /// The new point gets the index n.
+    fn add_point_on_line(&self, line: &Set) -> Result<BasesMatroid, String>
+    where
+        Self: Sized,
+    {
+        if !line.difference(&Set::of_size(self.n())).is_empty() {
+            return Err(format!("{} is not a subset of the ground set", line));
+        }
+
+        let r = self.rank(line);
+        if r != 2 {
+            return Err(format!("{} has rank {}, so it is not a line", line, r));
+        }
+
+        if let Some(e) = (0..self.n())
+            .filter(|e| !line.contains_element(*e))
+            .find(|e| self.rank(&line.add_element(*e)) == r)
+        {
+            return Err(format!(
+                "{} is not a flat, it spans the element {}",
+                line, e
+            ));
+        }
+
+        let extension = self.principal_extension(line);
+        Ok(BasesMatroid::new(
+            extension.bases(),
+            extension.n(),
+            extension.k(),
+        ))
+    }
+
     /// the combinatorial derived matroid
+    /// A matroid without circuits (e.g. a free matroid) gives the empty matroid U(0, 0).
     fn combinatorial_derived(&self) -> CombinatorialDerived
     where
         Self: Sync + Sized,
@@ -177,6 +826,15 @@ pub trait Matroid {
         CombinatorialDerived::from_matroid(self)
     }
 
+    /// Iterator over the iterated combinatorial derived matroids δM, δ²M, ..., up to the given
+    /// depth. See [`CombinatorialDerivedIter`].
+    fn combinatorial_derived_iter(&self, depth: usize) -> CombinatorialDerivedIter<'_, Self>
+    where
+        Self: Sync + Sized,
+    {
+        CombinatorialDerivedIter::new(self, depth)
+    }
+
     /// checks if the matroid is uniform
     /// (i.e. if it has exactly binomial(n, k)=nCk bases)
     /// This will count the number of bases, so it will also generate all the bases, and is a
@@ -195,61 +853,846 @@ pub trait Matroid {
             return false;
         }
 
+        // a matroid is determined by its bases, and the bases of a dual are the complements of
+        // the bases of the matroid it is the dual of, so these can be compared directly
+        if let Some(primal_bases) = other.bases_of_dual() {
+            let ground_set = Set::of_size(self.n());
+            let mut dual_bases: Vec<usize> = primal_bases
+                .iter()
+                .map(|b| usize::from(ground_set.difference(b)))
+                .collect();
+            let mut bases: Vec<usize> = self.bases().iter().map(usize::from).collect();
+            dual_bases.sort();
+            bases.sort();
+            return bases == dual_bases;
+        }
+
+        // equal matroids have the same number of bases containing each element, and the same
+        // number of circuits of each size, which are cheaper to check than all the subsets.
+        // Finding all the circuits can be more expensive than the full check, so only the small
+        // circuits (loops, parallel pairs and triangles) are counted.
+        if self.element_basis_counts() != other.element_basis_counts() {
+            return false;
+        }
+        if small_circuit_sizes(self) != small_circuit_sizes(other) {
+            return false;
+        }
+
         // they must have the same independent and dependent sets
         SetIterator::new(self.n())
             .all(|set| self.is_independent(&set) == other.is_independent(&set))
     }
 
-    /// stores the matroid in a file
-    /// automatically adds the extension .matroid to the path
-    fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
-        let storage_matroid = StoredMatroid {
-            n: self.n(),
-            k: self.k(),
-            bases: self.bases(),
-        };
-        storage_matroid.to_file(path)
+    /// If the matroid is the dual of another matroid, the bases of that matroid.
+    /// This lets is_equal compare a matroid to a dual through the bases, instead of checking the
+    /// independence of every subset.
+    fn bases_of_dual(&self) -> Option<Vec<Set>> {
+        None
     }
 
-    /// The betti-numbers of the matroid
-    fn betti(&self) -> BettiNumbers
-    where
-        Self: Sized + Sync,
-    {
-        BettiNumbers::new(self)
+    /// checks if the matroid is a quotient of the other matroid on the same ground set, i.e. if
+    /// every flat of self is a flat of the other matroid. Equivalently, for all X ⊆ Y, the rank
+    /// grows at least as much from X to Y in the other matroid as in self.
+    fn is_quotient_of<M: Matroid>(&self, other: &M) -> bool {
+        self.n() == other.n()
+            && SetIterator::new(self.n()).all(|x| !self.is_flat(&x) || other.is_flat(&x))
     }
 
-    /// the restriction of self to the set
-    fn restrict(&self, element: &Set) -> BasesMatroid {
-        let rank = self.rank(element);
-        let n = element.size();
+    /// checks if the matroid is the image of the other matroid on the same ground set under the
+    /// identity weak map, i.e. if every independent set of self is independent in the other
+    /// matroid. Equivalently, r(X) is at most the rank of X in the other matroid for every X.
+    /// A quotient is a weak map image.
+    fn is_weak_map_image_of<M: Matroid>(&self, other: &M) -> bool {
+        self.n() == other.n() && SetIterator::new(self.n()).all(|x| self.rank(&x) <= other.rank(&x))
+    }
 
-        let bases = SetIterator::new(n)
-            .size_limit(rank)
-            .equal()
-            .filter(|s| self.is_independent(&s.extend(element)))
-            .collect();
+    /// checks if the matroid is isomorphic to another matroid,
+    /// i.e. if there is a bijection of the ground sets that preserves the rank.
+    ///
+    /// This is a backtracking search over all bijections, where a partial bijection is discarded
+    /// as soon as it does not preserve the rank of some subset of the elements mapped so far.
+    /// It is only feasible for small matroids.
+    fn is_isomorphic<M: Matroid>(&self, other: &M) -> bool {
+        if self.n() != other.n() || self.k() != other.k() {
+            return false;
+        }
 
-        BasesMatroid::new(bases, n, rank)
+        extend_isomorphism(self, other, &mut Vec::with_capacity(self.n()))
     }
 
-    /// The euler characteristic of the matroid
-    fn euler_characteristic(&self) -> i32 {
-        (0..=self.k())
-            .map(|i| {
-                SetIterator::new(self.n())
-                    .size_limit(i)
-                    .equal()
-                    .filter(|s| self.is_independent(s))
-                    .count() as i32
-                    * if i % 2 == 0 { -1 } else { 1 }
-            })
-            .sum()
+    /// checks if the matroid has a minor that is isomorphic to the other matroid.
+    /// See [`Matroid::minor_witness`] for the sets that are deleted and contracted.
+    fn has_minor<M: Matroid>(&self, other: &M) -> bool {
+        self.minor_witness(other).is_some()
     }
 
-    /// The betti number of the given subset
-    fn betti_num(&self, sigma: &Set) -> usize {
-        if self.is_cycle(sigma) {
+    /// A pair (D, C) of sets such that M / C \ D is isomorphic to the other matroid, or None if
+    /// the matroid has no such minor.
+    ///
+    /// If N = M / C \ D is a minor of M, then C and D can be chosen such that C is independent and
+    /// D is coindependent. Then |C| = r(M) - r(N) and |D| = |E(M)| - |E(N)| - |C|, so only such
+    /// pairs of sets are checked. The number of bases of a candidate minor is compared before
+    /// the expensive isomorphism test.
+    fn minor_witness<M: Matroid>(&self, other: &M) -> Option<(Set, Set)> {
+        if self.k() < other.k() || self.n() - self.k() < other.n() - other.k() {
+            return None;
+        }
+
+        let n = self.n();
+        let contract_size = self.k() - other.k();
+        let delete_size = n - other.n() - contract_size;
+        let ground_set = Set::of_size(n);
+        let bases = other.bases().len();
+
+        SetIterator::new(n)
+            .size_limit(contract_size)
+            .equal()
+            .filter(|contract| self.is_independent(contract))
+            .find_map(|contract| {
+                let rest = ground_set.difference(&contract);
+                SetIterator::new(rest.size())
+                    .size_limit(delete_size)
+                    .equal()
+                    .map(|delete| delete.extend(&rest))
+                    .filter(|delete| self.rank(&ground_set.difference(delete)) == self.k())
+                    .find(|delete| {
+                        let minor = self.minor(delete, &contract);
+                        minor.bases().len() == bases && minor.is_isomorphic(other)
+                    })
+                    .map(|delete| (delete, contract))
+            })
+    }
+
+    /// One representative of every isomorphism class of minors of the matroid, including the
+    /// matroid itself and the empty matroid.
+    ///
+    /// Every minor can be written as M / C \ D with C independent and D coindependent, so only
+    /// such pairs are considered, but there are still up to 3^n of them, and each new minor has
+    /// to be checked for isomorphism against the representatives found so far. In practice this
+    /// limits the matroid to about n = 10 elements.
+    fn minor_closure(&self) -> Vec<BasesMatroid> {
+        let n = self.n();
+        let ground_set = Set::of_size(n);
+        let mut representatives: Vec<BasesMatroid> = Vec::new();
+
+        for contract in SetIterator::new(n).filter(|c| self.is_independent(c)) {
+            let rest = ground_set.difference(&contract);
+            for delete in SetIterator::new(rest.size())
+                .map(|d| d.extend(&rest))
+                .filter(|d| self.rank(&ground_set.difference(d)) == self.k())
+            {
+                let minor = self.minor(&delete, &contract);
+                let bases = minor.bases().len();
+                if !representatives.iter().any(|r| {
+                    r.n() == minor.n()
+                        && r.k() == minor.k()
+                        && r.bases().len() == bases
+                        && r.is_isomorphic(&minor)
+                }) {
+                    representatives.push(minor);
+                }
+            }
+        }
+
+        representatives
+    }
+
+    /// checks if the matroid is binary, i.e. representable over GF(2).
+    ///
+    /// A matroid is binary if and only if it does not have U(2, 4) as a minor (Tutte). Instead of
+    /// a minor search, this looks for a representation over GF(2) in standard form. Over GF(2)
+    /// the fundamental circuits of a basis determine the matrix, so only one matrix is checked.
+    fn is_binary(&self) -> bool
+    where
+        Self: Sized,
+    {
+        super::representation::find_representation::<PrimeFieldElt<GF2>, _>(self).is_some()
+    }
+
+    /// checks if the matroid is ternary, i.e. representable over GF(3).
+    ///
+    /// A matroid is ternary if and only if it has none of U(2, 5), U(3, 5), the Fano matroid F_7
+    /// and its dual F_7^* as a minor (Reid, Bixby and Seymour). Instead of a search for these
+    /// minors, this looks for a representation over GF(3) in standard form, which takes 2^m steps
+    /// for m free entries of the matrix, see MatrixMatroid::is_representable_over.
+    fn is_ternary(&self) -> bool
+    where
+        Self: Sized,
+    {
+        super::representation::find_representation::<crate::field::GF3, _>(self).is_some()
+    }
+
+    /// checks if the matroid is regular, i.e. representable over every field.
+    ///
+    /// A matroid is regular if and only if it is both binary and ternary (Tutte). Equivalently,
+    /// it is binary and has neither the Fano matroid F_7 nor its dual F_7^* as a minor.
+    fn is_regular(&self) -> bool
+    where
+        Self: Sized,
+    {
+        self.is_binary() && self.is_ternary()
+    }
+
+    /// checks if the matroid is cographic, i.e. the dual of the cycle matroid of a graph.
+    ///
+    /// A matroid is cographic if and only if it has none of U(2, 4), F_7, F_7^*, M(K_5) and
+    /// M(K_3,3) as a minor (Tutte), which are the minors checked here.
+    fn is_cographic(&self) -> bool
+    where
+        Self: Sized,
+    {
+        let fano = super::examples::fano();
+        let k5: Vec<(usize, usize)> = (0..5)
+            .flat_map(|u| ((u + 1)..5).map(move |v| (u, v)))
+            .collect();
+        let k33: Vec<(usize, usize)> = (0..3).flat_map(|u| (3..6).map(move |v| (u, v))).collect();
+        let k5 = super::GraphicMatroid::new(5, &k5).unwrap();
+        let k33 = super::GraphicMatroid::new(6, &k33).unwrap();
+
+        self.avoids_minors(&[
+            super::UniformMatroid::new(2, 4).to_bases_matroid(),
+            fano.to_bases_matroid(),
+            fano.dual().to_bases_matroid(),
+            k5.to_bases_matroid(),
+            k33.to_bases_matroid(),
+        ])
+    }
+
+    /// checks if the matroid is binary by the excluded minor characterization (Tutte): it does
+    /// not have U(2, 4) as a minor. The answer is the same as for [`Matroid::is_binary`], which
+    /// looks for a representation instead and is usually much faster.
+    fn is_binary_via_minors(&self) -> bool
+    where
+        Self: Sized,
+    {
+        self.avoids_minors(&[super::UniformMatroid::new(2, 4).to_bases_matroid()])
+    }
+
+    /// checks if the matroid has none of the matroids as a minor.
+    ///
+    /// `Matroid` has generic methods, so it can not be used as `&dyn Matroid`. Instead the minors
+    /// are given as [`BasesMatroid`]s, so that a list of excluded minors of different types can
+    /// be checked in one call, with [`Matroid::to_bases_matroid`] to convert them.
+    fn avoids_minors(&self, minors: &[BasesMatroid]) -> bool {
+        !minors.iter().any(|minor| self.has_minor(minor))
+    }
+
+    /// checks if the matroid is graphic, i.e. the cycle matroid of a graph.
+    /// See [`Matroid::graph_representation`] for the graph.
+    fn is_graphic(&self) -> bool
+    where
+        Self: Sized,
+    {
+        self.graph_representation().is_some()
+    }
+
+    /// A presentation of the matroid as a transversal matroid, or None if it is not transversal.
+    ///
+    /// If the matroid is transversal, the complements of the sets of its maximal presentation are
+    /// cyclic flats, and the number of sets avoiding a cyclic flat X is r(M) - r(X) (Bonin, Kung
+    /// and de Mier). So the number of copies of E \ X is found by Möbius inversion over the
+    /// cyclic flats containing X, and the matroid is transversal exactly when these numbers are
+    /// non-negative and the presentation gives back the matroid. Comparing the matroids checks
+    /// every subset, so this is only feasible for small matroids.
+    fn transversal_presentation(&self) -> Option<Vec<Set>>
+    where
+        Self: Sized,
+    {
+        let ground_set = Set::of_size(self.n());
+        let mut cyclic_flats = self.cyclic_flats();
+        // a cyclic flat comes after every cyclic flat containing it
+        cyclic_flats.sort_by_key(|(flat, _)| std::cmp::Reverse(flat.size()));
+
+        let mut copies: Vec<usize> = Vec::with_capacity(cyclic_flats.len());
+        for (i, (x, rank)) in cyclic_flats.iter().enumerate() {
+            let above: usize = cyclic_flats[..i]
+                .iter()
+                .zip(&copies)
+                .filter(|((y, _), _)| x <= y)
+                .map(|(_, c)| c)
+                .sum();
+            copies.push((self.k() - rank).checked_sub(above)?);
+        }
+
+        let sets: Vec<Set> = cyclic_flats
+            .iter()
+            .zip(copies)
+            .flat_map(|((flat, _), c)| std::iter::repeat_n(ground_set.difference(flat), c))
+            .collect();
+        let presented = super::TransversalMatroid::new(self.n(), sets.clone())?;
+
+        self.is_equal(&presented).then_some(sets)
+    }
+
+    /// checks if the matroid is transversal.
+    /// See [`Matroid::transversal_presentation`] for a presentation.
+    fn is_transversal(&self) -> bool
+    where
+        Self: Sized,
+    {
+        self.transversal_presentation().is_some()
+    }
+
+    /// A graph whose cycle matroid is the matroid, or None if the matroid is not graphic.
+    /// Returns the number of vertices and the list of edges, where edge e is the element e.
+    /// The graph is connected, and a loop is an edge from a vertex to itself.
+    ///
+    /// The graph is found by searching for vertex stars among the cocycles of a binary
+    /// representation, so it is only feasible for small matroids.
+    fn graph_representation(&self) -> Option<(usize, Vec<(usize, usize)>)>
+    where
+        Self: Sized,
+    {
+        find_graph(self)
+    }
+
+    /// Checks that the rank function satisfies the rank axioms, and that the rank of the ground
+    /// set is k. Returns a description of the first violation found.
+    ///
+    /// This calculates the rank of all 2^n subsets, and checks submodularity for all pairs of
+    /// subsets, so it takes 4^n steps. It is only feasible for small matroids (n up to about 12).
+    fn check_rank_axioms(&self) -> Result<(), String> {
+        super::verify::verify_rank_axioms(self).map_err(|violation| violation.to_string())
+    }
+
+    /// Format the sets as a comma separated list of sets, where the element e is written as
+    /// labels[e], e.g. "{a,b}, {a,c}".
+    /// Panics if there is not exactly one label for every element.
+    fn format_sets(&self, sets: &[Set], labels: &[&str]) -> String {
+        assert_eq!(
+            labels.len(),
+            self.n(),
+            "need one label for each of the {} elements",
+            self.n()
+        );
+
+        sets.iter()
+            .map(|set| {
+                let elements: Vec<&str> = set.bits().map(|e| labels[e]).collect();
+                format!("{{{}}}", elements.join(","))
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// The representation of the matroid used when it is stored.
+    /// By default this is the list of bases, but matroids that are determined by a few parameters
+    /// may be stored more compactly.
+    fn to_stored(&self) -> StoredMatroidKind {
+        StoredMatroidKind::Bases(StoredMatroid {
+            n: self.n(),
+            k: self.k(),
+            // sorted, so that saving the same matroid always gives the same output
+            bases: self.sorted_bases(),
+        })
+    }
+
+    /// stores the matroid in a file
+    /// automatically adds the extension .matroid to the path
+    fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        self.to_stored().to_file(path)
+    }
+
+    /// writes the matroid to the writer in the given format
+    fn write_to<W: Write>(
+        &self,
+        writer: &mut W,
+        format: StorageFormat,
+    ) -> Result<(), StorageError> {
+        self.to_stored().write_to(writer, format)
+    }
+
+    /// The betti-numbers of the matroid
+    fn betti(&self) -> BettiNumbers
+    where
+        Self: Sized + Sync,
+    {
+        BettiNumbers::new(self)
+    }
+
+    /// The betti-numbers of the dual matroid
+    ///
+    /// By Alexander duality (with Hochster's formula), these are determined by the lattice of flats
+    /// of self: b_{i,j}(M*) is the sum of |μ(F, E)| over all flats F of M of rank k - i and
+    /// cardinality n - j, where μ is the Möbius function of the lattice of flats.
+    /// In particular a self-dual matroid (like U(3, 6)) has the same betti numbers as its dual.
+    fn dual_betti(&self) -> BettiNumbers
+    where
+        Self: Sized + Sync,
+    {
+        BettiNumbers::new(&self.dual())
+    }
+
+    /// the restriction of self to the set
+    fn restrict(&self, element: &Set) -> BasesMatroid {
+        let rank = self.rank(element);
+        let n = element.size();
+
+        let bases = SetIterator::new(n)
+            .size_limit(rank)
+            .equal()
+            .filter(|s| self.is_independent(&s.extend(element)))
+            .collect();
+
+        BasesMatroid::new(bases, n, rank)
+    }
+
+    /// The deletion M \ D as a lazy view, where the remaining elements are re-indexed to
+    /// 0, 1, ..., n - 1. See [`Minor`] for the combination of deletion and contraction.
+    fn delete(&self, delete: &Set) -> Minor<'_, Self>
+    where
+        Self: Sized,
+    {
+        Minor::new(self, delete, &Set::empty())
+    }
+
+    /// The contraction M / C as a lazy view, where the remaining elements are re-indexed to
+    /// 0, 1, ..., n - 1. See [`Minor`] for the combination of deletion and contraction.
+    fn contract(&self, contract: &Set) -> Minor<'_, Self>
+    where
+        Self: Sized,
+    {
+        Minor::new(self, &Set::empty(), contract)
+    }
+
+    /// the minor of self where the elements in delete are deleted and the elements in contract
+    /// are contracted. The two sets should be disjoint.
+    /// The remaining elements keep their order, but are re-indexed to 0, 1, ..., n - 1.
+    /// This finds the bases of the minor, for a lazy view use [`Minor::new`].
+    fn minor(&self, delete: &Set, contract: &Set) -> BasesMatroid {
+        debug_assert!(delete.intersect(contract).is_empty());
+
+        let remaining = Set::of_size(self.n()).difference(&delete.union(contract));
+        let contract_rank = self.rank(contract);
+        let rank = self.rank(&remaining.union(contract)) - contract_rank;
+        let n = remaining.size();
+
+        let bases = SetIterator::new(n)
+            .size_limit(rank)
+            .equal()
+            .filter(|s| self.rank(&s.extend(&remaining).union(contract)) - contract_rank == rank)
+            .collect();
+
+        BasesMatroid::new(bases, n, rank)
+    }
+
+    /// The deletion M \ e and the contraction M / e of the single element e.
+    /// The elements after e are shifted down by one in both matroids.
+    ///
+    /// Both are found from one enumeration of the bases of self: if e is not a coloop, the bases
+    /// of M \ e are the bases avoiding e, and if e is not a loop, the bases of M / e are the bases
+    /// containing e, with e removed.
+    fn delete_contract(&self, e: usize) -> (BasesMatroid, BasesMatroid) {
+        let n = self.n();
+        let k = self.k();
+        let bases = self.bases();
+
+        // remove e, and shift the later elements down
+        let without_e = |set: &Set| -> Set {
+            set.bits()
+                .filter(|&x| x != e)
+                .map(|x| if x > e { x - 1 } else { x })
+                .collect::<Vec<usize>>()
+                .into()
+        };
+        let (containing, avoiding): (Vec<Set>, Vec<Set>) =
+            bases.iter().partition(|b| b.contains_element(e));
+
+        let deletion = if avoiding.is_empty() {
+            // e is a coloop
+            BasesMatroid::new(containing.iter().map(without_e).collect(), n - 1, k - 1)
+        } else {
+            BasesMatroid::new(avoiding.iter().map(without_e).collect(), n - 1, k)
+        };
+
+        let contraction = if containing.is_empty() {
+            // e is a loop
+            BasesMatroid::new(avoiding.iter().map(without_e).collect(), n - 1, k)
+        } else {
+            BasesMatroid::new(containing.iter().map(without_e).collect(), n - 1, k - 1)
+        };
+
+        (deletion, contraction)
+    }
+
+    /// The number of subsets of every corank and nullity.
+    /// Element [r][s] is the number of subsets X with r(E) - r(X) = r and |X| - r(X) = s.
+    ///
+    /// The Tutte polynomial, the number of independent sets of every size (the subsets with
+    /// nullity 0) and the number of spanning sets (the subsets with corank 0) can all be found
+    /// from this table.
+    fn corank_nullity_distribution(&self) -> Vec<Vec<usize>> {
+        let k = self.k();
+        let mut counts = vec![vec![0; self.n() - k + 1]; k + 1];
+        for s in SetIterator::new(self.n()) {
+            let r = self.rank(&s);
+            counts[k - r][s.size() - r] += 1;
+        }
+        counts
+    }
+
+    /// The Tutte polynomial T(x, y) of the matroid.
+    /// Element [i][j] is the coefficient of x^i y^j.
+    ///
+    /// Calculated from the corank-nullity formula
+    /// T(x, y) = sum over X of (x - 1)^(r(E) - r(X)) (y - 1)^(|X| - r(X)),
+    /// so the rank of every subset is calculated.
+    fn tutte_polynomial(&self) -> Vec<Vec<i64>> {
+        let k = self.k();
+        let nullity = self.n() - k;
+        let counts = self.corank_nullity_distribution();
+
+        // expand (x - 1)^a (y - 1)^b
+        let sign = |e: usize| if e % 2 == 0 { 1 } else { -1 };
+        let mut tutte = vec![vec![0i64; nullity + 1]; k + 1];
+        for (a, row) in counts.iter().enumerate() {
+            for (b, &count) in row.iter().enumerate() {
+                for (i, tutte_row) in tutte.iter_mut().enumerate().take(a + 1) {
+                    for (j, t) in tutte_row.iter_mut().enumerate().take(b + 1) {
+                        *t += count as i64
+                            * binomial(a as i64, i as i64)
+                            * binomial(b as i64, j as i64)
+                            * sign(a - i + b - j);
+                    }
+                }
+            }
+        }
+
+        tutte
+    }
+
+    /// The internally and externally active elements of the basis, with respect to the ordering
+    /// of the elements from the smallest to the largest.
+    ///
+    /// An element e of the basis is internally active if it is the smallest element of its
+    /// fundamental cocircuit, and an element e outside the basis is externally active if it is
+    /// the smallest element of its fundamental circuit. Both are found from whether e can be
+    /// exchanged with f, i.e. if B - f + e is a basis, so no circuits are enumerated.
+    fn activities(&self, basis: &Set, ordering: &[usize]) -> (Set, Set) {
+        debug_assert_eq!(ordering.len(), self.n());
+        let k = self.k();
+        let mut position = vec![0; self.n()];
+        for (i, &e) in ordering.iter().enumerate() {
+            position[e] = i;
+        }
+
+        let outside: Vec<usize> = (0..self.n())
+            .filter(|&e| !basis.contains_element(e))
+            .collect();
+        // b in the basis can be exchanged with e outside it
+        let exchangeable =
+            |b: usize, e: usize| self.rank(&basis.remove_element(b).add_element(e)) == k;
+
+        let internal = basis
+            .bits()
+            .filter(|&b| {
+                outside
+                    .iter()
+                    .all(|&e| position[e] > position[b] || !exchangeable(b, e))
+            })
+            .fold(Set::empty(), |acc, b| acc.add_element(b));
+        let external = outside
+            .iter()
+            .copied()
+            .filter(|&e| {
+                basis
+                    .bits()
+                    .all(|b| position[b] > position[e] || !exchangeable(b, e))
+            })
+            .fold(Set::empty(), |acc, e| acc.add_element(e));
+
+        (internal, external)
+    }
+
+    /// The Tutte polynomial as the sum over the bases of x^i y^e, where i and e are the numbers
+    /// of internally and externally active elements in the natural order of the elements.
+    /// Element [i][j] is the coefficient of x^i y^j, as in tutte_polynomial.
+    fn tutte_polynomial_by_activities(&self) -> Vec<Vec<i64>> {
+        let ordering: Vec<usize> = (0..self.n()).collect();
+        let mut tutte = vec![vec![0i64; self.n() - self.k() + 1]; self.k() + 1];
+        for basis in self.bases() {
+            let (internal, external) = self.activities(&basis, &ordering);
+            tutte[internal.size()][external.size()] += 1;
+        }
+
+        tutte
+    }
+
+    /// The flow polynomial of the matroid, F(t) = (-1)^(|E| - r(E)) T(0, 1 - t).
+    /// Element i is the coefficient of t^i.
+    ///
+    /// For the cycle matroid of a graph, F(t) is the number of nowhere-zero flows of the graph
+    /// with values in an abelian group of order t.
+    fn flow_polynomial(&self) -> Vec<i64> {
+        let tutte = self.tutte_polynomial();
+        let nullity = self.n() - self.k();
+        let sign = |e: usize| if e % 2 == 0 { 1 } else { -1 };
+
+        // T(0, 1 - t) = sum over j of T[0][j] (1 - t)^j
+        let mut flow = vec![0i64; nullity + 1];
+        for (j, coefficient) in tutte[0].iter().enumerate() {
+            for (m, f) in flow.iter_mut().enumerate().take(j + 1) {
+                *f += sign(nullity) * coefficient * binomial(j as i64, m as i64) * sign(m);
+            }
+        }
+
+        flow
+    }
+
+    /// The characteristic polynomial of the matroid, χ(t) = (-1)^r(E) T(1 - t, 0).
+    /// Element i is the coefficient of t^i.
+    ///
+    /// For the cycle matroid of a connected graph, t χ(t) is the chromatic polynomial, and for a
+    /// real hyperplane arrangement, |χ(-1)| is the number of regions.
+    fn characteristic_polynomial(&self) -> Vec<i64> {
+        let tutte = self.tutte_polynomial();
+        let k = self.k();
+        let sign = |e: usize| if e % 2 == 0 { 1 } else { -1 };
+
+        // T(1 - t, 0) = sum over i of T[i][0] (1 - t)^i
+        let mut chi = vec![0i64; k + 1];
+        for (i, row) in tutte.iter().enumerate() {
+            for (m, c) in chi.iter_mut().enumerate().take(i + 1) {
+                *c += sign(k) * row[0] * binomial(i as i64, m as i64) * sign(m);
+            }
+        }
+
+        chi
+    }
+
+    /// The Möbius invariant μ(M) = χ(0), the Möbius function μ(∅, E) of the lattice of flats
+    /// (when the matroid has no loops).
+    fn mobius_invariant(&self) -> i64 {
+        self.characteristic_polynomial()[0]
+    }
+
+    /// The beta invariant of Crapo, β(M) = (-1)^r(E) sum over X of (-1)^|X| r(X), which is also
+    /// (-1)^(r(E) - 1) χ'(1).
+    /// It is non-negative, and for matroids with at least two elements it is zero exactly when
+    /// the matroid is disconnected, and one exactly when it is a series-parallel network.
+    ///
+    /// The sum is calculated directly, with one rank query for every subset, so neither the
+    /// Tutte polynomial nor the characteristic polynomial is needed.
+    fn beta_invariant(&self) -> i64 {
+        let sum: i64 = SetIterator::new(self.n())
+            .map(|s| {
+                let r = self.rank(&s) as i64;
+                if s.size() % 2 == 0 {
+                    r
+                } else {
+                    -r
+                }
+            })
+            .sum();
+
+        if self.k() % 2 == 0 {
+            sum
+        } else {
+            -sum
+        }
+    }
+
+    /// The Hilbert series of the Chow ring of the matroid, element i is the dimension of the
+    /// degree i part. The Chow ring of a matroid of rank r lives in degrees 0 to r - 1, and the
+    /// dimensions are palindromic (Adiprasito, Huh and Katz).
+    ///
+    /// The Feichtner-Yuzvinsky basis consists of the monomials x_F1^a1 ... x_Fm^am for chains of
+    /// flats cl(∅) < F1 < ... < Fm with 1 <= ai < r(Fi) - r(F(i-1)), so the series is found from
+    /// the lattice of flats, by summing the products of t + ... + t^(r(Fi) - r(F(i-1)) - 1) over
+    /// the chains ending at each flat.
+    fn chow_ring_hilbert(&self) -> Vec<usize> {
+        let k = self.k();
+        let flats: Vec<(Set, usize)> = (0..=k)
+            .flat_map(|rank| {
+                self.flats_of_rank(rank)
+                    .into_iter()
+                    .map(move |flat| (flat, rank))
+            })
+            .collect();
+
+        // chains[i] is the sum over the chains from the bottom flat to flats[i]
+        let mut chains: Vec<Vec<usize>> = Vec::with_capacity(flats.len());
+        for (g, rank_g) in &flats {
+            let mut series = vec![0; k.max(1)];
+            if *rank_g == 0 {
+                series[0] = 1;
+            }
+
+            for ((f, rank_f), below) in flats.iter().zip(&chains) {
+                if rank_f >= rank_g || !f.difference(g).is_empty() {
+                    continue;
+                }
+                // multiply by t + ... + t^(r(G) - r(F) - 1)
+                for a in 1..(rank_g - rank_f) {
+                    for (i, &c) in below.iter().enumerate().take(k - a) {
+                        series[i + a] += c;
+                    }
+                }
+            }
+            chains.push(series);
+        }
+
+        let mut hilbert = vec![0; k.max(1)];
+        for series in chains {
+            for (h, c) in hilbert.iter_mut().zip(series) {
+                *h += c;
+            }
+        }
+        hilbert
+    }
+
+    /// The Kazhdan-Lusztig polynomial of the matroid, element i is the coefficient of t^i.
+    /// There are no trailing zero coefficients, so equal polynomials give equal vectors.
+    ///
+    /// It is the unique polynomial P_M of degree less than r(M) / 2 (and P = 1 for rank 0) with
+    /// t^r(M) P_M(1/t) = sum over the flats F of χ_(M|F)(t) P_(M/F)(t) (Elias, Proudfoot and
+    /// Wakefield). Both sides only depend on intervals in the lattice of flats, so the
+    /// polynomials of the contractions M/F are found once for every flat, from the top of the
+    /// lattice and down, and the characteristic polynomials of the restrictions M|F from the
+    /// Möbius function of the lattice. This is feasible for matroids up to about n = 10.
+    fn kazhdan_lusztig_polynomial(&self) -> Vec<i64> {
+        let k = self.k();
+        let flats: Vec<(Set, usize)> = (0..=k)
+            .flat_map(|rank| {
+                self.flats_of_rank(rank)
+                    .into_iter()
+                    .map(move |flat| (flat, rank))
+            })
+            .collect();
+        let m = flats.len();
+        let below = |i: usize, j: usize| flats[i].0 <= flats[j].0;
+
+        // the Möbius function of the lattice, the flats are sorted by rank
+        let mut mobius = vec![vec![0i64; m]; m];
+        for (i, row) in mobius.iter_mut().enumerate() {
+            row[i] = 1;
+            for j in (i + 1)..m {
+                if below(i, j) {
+                    let sum: i64 = (i..j)
+                        .filter(|&l| below(i, l) && below(l, j))
+                        .map(|l| row[l])
+                        .sum();
+                    row[j] = -sum;
+                }
+            }
+        }
+
+        // the characteristic polynomial of the interval [F_i, F_j]
+        let chi = |i: usize, j: usize| {
+            let mut chi = vec![0i64; flats[j].1 - flats[i].1 + 1];
+            for l in (i..=j).filter(|&l| below(i, l) && below(l, j)) {
+                chi[flats[j].1 - flats[l].1] += mobius[i][l];
+            }
+            chi
+        };
+
+        // polynomials[i] is the polynomial of the interval [F_i, E]
+        let mut polynomials: Vec<Vec<i64>> = vec![Vec::new(); m];
+        for i in (0..m).rev() {
+            let d = k - flats[i].1;
+            let mut rest = vec![0i64; d + 1];
+            for j in ((i + 1)..m).filter(|&j| below(i, j)) {
+                for (a, x) in chi(i, j).iter().enumerate() {
+                    for (b, y) in polynomials[j].iter().enumerate() {
+                        rest[a + b] += x * y;
+                    }
+                }
+            }
+
+            // t^d P(1/t) only has terms of degree above d / 2, so the lower coefficients of
+            // t^d P(1/t) - P(t) = rest come from -P(t)
+            polynomials[i] = if d == 0 {
+                vec![1]
+            } else {
+                (0..d).take_while(|l| 2 * l < d).map(|l| -rest[l]).collect()
+            };
+        }
+
+        let mut polynomial = polynomials.swap_remove(0);
+        while polynomial.len() > 1 && polynomial.last() == Some(&0) {
+            polynomial.pop();
+        }
+        polynomial
+    }
+
+    /// The catenary data of the matroid, which determines Derksen's G-invariant.
+    ///
+    /// For every maximal chain of flats cl(∅) = X_0 < X_1 < ... < X_r = E, the composition is
+    /// (|X_0|, |X_1 \\ X_0|, ..., |X_r \\ X_(r-1)|), and the map takes each composition to the
+    /// number of chains with it. The G-invariant specializes to the Tutte polynomial, but it
+    /// separates some matroids that have the same Tutte polynomial.
+    fn g_invariant(&self) -> BTreeMap<Vec<usize>, usize> {
+        let k = self.k();
+        let flats_by_rank: Vec<Vec<Set>> = (0..=k).map(|rank| self.flats_of_rank(rank)).collect();
+
+        // the compositions of the chains from each flat of the rank to the ground set
+        let mut above: Vec<BTreeMap<Vec<usize>, usize>> = vec![BTreeMap::from([(Vec::new(), 1)])];
+        for rank in (0..k).rev() {
+            let covers = &flats_by_rank[rank + 1];
+            above = flats_by_rank[rank]
+                .iter()
+                .map(|f| {
+                    let mut chains = BTreeMap::new();
+                    for (g, chains_from_g) in covers.iter().zip(&above) {
+                        if !f.difference(g).is_empty() {
+                            continue;
+                        }
+                        let jump = g.difference(f).size();
+                        for (composition, count) in chains_from_g {
+                            let mut composition = composition.clone();
+                            composition.insert(0, jump);
+                            *chains.entry(composition).or_insert(0) += count;
+                        }
+                    }
+                    chains
+                })
+                .collect();
+        }
+
+        // there is exactly one flat of rank 0, the closure of the empty set
+        let loops = flats_by_rank[0][0].size();
+        above
+            .swap_remove(0)
+            .into_iter()
+            .map(|(mut composition, count)| {
+                composition.insert(0, loops);
+                (composition, count)
+            })
+            .collect()
+    }
+
+    /// The euler characteristic of the matroid.
+    /// This is the reduced euler characteristic of the independence complex, see
+    /// reduced_euler_characteristic.
+    fn euler_characteristic(&self) -> i32 {
+        (0..=self.k())
+            .map(|i| {
+                SetIterator::new(self.n())
+                    .size_limit(i)
+                    .equal()
+                    .filter(|s| self.is_independent(s))
+                    .count() as i32
+                    * if i % 2 == 0 { -1 } else { 1 }
+            })
+            .sum()
+    }
+
+    /// The reduced euler characteristic of the independence complex, i.e. the sum of (-1)^dim(F)
+    /// over all faces F, where the faces are the independent sets, and an independent set I has
+    /// dimension |I| - 1. The empty set is the face of dimension -1, and counts as -1.
+    ///
+    /// With this convention it is the same as euler_characteristic, and it is equal to
+    /// (-1)^(k - 1) T(0, 1), where T is the Tutte polynomial.
+    fn reduced_euler_characteristic(&self) -> i32 {
+        SetIterator::new(self.n())
+            .filter(|s| self.is_independent(s))
+            .map(|s| if s.size() % 2 == 0 { -1 } else { 1 })
+            .sum()
+    }
+
+    /// The betti number of the given subset
+    fn betti_num(&self, sigma: &Set) -> usize {
+        if self.is_cycle(sigma) {
             let r = self.rank(sigma);
             self.restrict(sigma).euler_characteristic() * if r % 2 == 0 { -1 } else { 1 }
         } else {
@@ -273,6 +1716,280 @@ pub trait Matroid {
     }
 }
 
+/// Extend the disjoint independent sets so that their union is as large as possible, and return
+/// the union. This is the matroid partition algorithm (Edmonds).
+///
+/// For every element outside the union, a shortest path is searched for in the exchange graph,
+/// where there is an edge from x to y when x can replace y in the set containing y. The path
+/// ends at an element that can be added to one of the sets directly, and moving the elements
+/// along a shortest path keeps every set independent. Every search takes at most n m tests of
+/// adding an element to a set, and the exchange tests of the elements that are not reached yet.
+fn extend_partition<M: Matroid + ?Sized>(matroid: &M, sets: &mut [Set]) -> Set {
+    let n = matroid.n();
+    let mut union = sets.iter().fold(Set::empty(), |acc, s| acc.union(s));
+
+    for start in 0..n {
+        if union.contains_element(start) {
+            continue;
+        }
+
+        // parent[y] = (x, i) when x replaces y in the set i
+        let mut parent: Vec<Option<(usize, usize)>> = vec![None; n];
+        let mut reached = Set::empty().add_element(start);
+        let mut queue = std::collections::VecDeque::from([start]);
+        let mut end = None;
+
+        'search: while let Some(x) = queue.pop_front() {
+            for (i, set) in sets.iter().enumerate() {
+                if set.contains_element(x) {
+                    continue;
+                }
+                let extended = set.add_element(x);
+                if matroid.is_independent(&extended) {
+                    end = Some((x, i));
+                    break 'search;
+                }
+                for y in set.bits() {
+                    if !reached.contains_element(y)
+                        && matroid.is_independent(&extended.remove_element(y))
+                    {
+                        reached = reached.add_element(y);
+                        parent[y] = Some((x, i));
+                        queue.push_back(y);
+                    }
+                }
+            }
+        }
+
+        if let Some((mut x, i)) = end {
+            sets[i] = sets[i].add_element(x);
+            while let Some((previous, j)) = parent[x] {
+                sets[j] = sets[j].remove_element(x).add_element(previous);
+                x = previous;
+            }
+            union = union.add_element(start);
+        }
+    }
+
+    union
+}
+
+/// The largest circuits counted by small_circuit_sizes
+const SMALL_CIRCUIT_SIZE: usize = 3;
+
+/// The number of circuits of each size up to SMALL_CIRCUIT_SIZE
+fn small_circuit_sizes<M: Matroid + ?Sized>(matroid: &M) -> Vec<usize> {
+    let mut sizes = vec![0; SMALL_CIRCUIT_SIZE + 1];
+    SetIterator::new(matroid.n())
+        .size_limit(SMALL_CIRCUIT_SIZE)
+        .smaller_equal()
+        .filter(|set| matroid.is_circuit(set))
+        .for_each(|set| sizes[set.size()] += 1);
+    sizes
+}
+
+/// Checks if the elements 0..n are connected when two elements are connected if they are in a
+/// common circuit.
+fn connected_by_circuits(n: usize, circuits: &[Set]) -> bool {
+    n <= 1 || components_by_circuits(n, circuits).len() == 1
+}
+
+/// The fundamental circuits of the elements outside a basis, found greedily
+fn fundamental_circuits<M: Matroid + ?Sized>(matroid: &M) -> Vec<Set> {
+    let k = matroid.k();
+    let basis = (0..matroid.n()).fold(Set::empty(), |basis, e| {
+        let extended = basis.add_element(e);
+        if matroid.rank(&extended) > basis.size() {
+            extended
+        } else {
+            basis
+        }
+    });
+
+    // b is in the fundamental circuit of e if e can replace b in the basis
+    (0..matroid.n())
+        .filter(|&e| !basis.contains_element(e))
+        .map(|e| {
+            basis
+                .bits()
+                .filter(|&b| matroid.rank(&basis.remove_element(b).add_element(e)) == k)
+                .fold(Set::empty().add_element(e), |acc, b| acc.add_element(b))
+        })
+        .collect()
+}
+
+/// The connected components of the minor of the matroid on the elements of ground not in
+/// contract, where the elements of contract are contracted.
+fn minor_components<M: Matroid + ?Sized>(matroid: &M, contract: Set, ground: Set) -> Vec<Set> {
+    let contract_rank = matroid.rank(&contract);
+    let rank = |set: &Set| matroid.rank(&set.union(&contract)) - contract_rank;
+    let elements = ground.difference(&contract);
+    let basis = elements.bits().fold(Set::empty(), |basis, e| {
+        let extended = basis.add_element(e);
+        if rank(&extended) > basis.size() {
+            extended
+        } else {
+            basis
+        }
+    });
+
+    let circuits: Vec<Set> = elements
+        .difference(&basis)
+        .bits()
+        .map(|e| {
+            basis
+                .bits()
+                .filter(|&b| rank(&basis.remove_element(b).add_element(e)) == basis.size())
+                .fold(Set::empty().add_element(e), |acc, b| acc.add_element(b))
+        })
+        .collect();
+
+    // the elements outside the minor end up alone in their classes
+    components_by_circuits(matroid.n(), &circuits)
+        .into_iter()
+        .filter(|c| *c <= elements)
+        .collect()
+}
+
+/// The normalized volume of the base polytope of the minor on the elements of ground not in
+/// contract, memoized by the pair of sets.
+fn polytope_volume<M: Matroid + ?Sized>(
+    matroid: &M,
+    contract: Set,
+    ground: Set,
+    memo: &mut HashMap<(Set, Set), u64>,
+) -> u64 {
+    if let Some(&volume) = memo.get(&(contract, ground)) {
+        return volume;
+    }
+
+    let elements = ground.difference(&contract);
+    let components = minor_components(matroid, contract, ground);
+    let volume = if components.len() != 1 {
+        // the product of the polytopes of the components
+        let mut dimension = 0;
+        let mut volume = 1;
+        for component in components {
+            let d = component.size() - 1;
+            dimension += d;
+            volume *= binomial(dimension, d) as u64
+                * polytope_volume(matroid, contract, contract.union(&component), memo);
+        }
+        volume
+    } else if elements.size() == 1 {
+        1
+    } else {
+        let contract_rank = matroid.rank(&contract);
+        let rank = |set: &Set| matroid.rank(&set.union(&contract)) - contract_rank;
+        let basis = elements.bits().fold(Set::empty(), |basis, e| {
+            let extended = basis.add_element(e);
+            if rank(&extended) > basis.size() {
+                extended
+            } else {
+                basis
+            }
+        });
+
+        // the proper non-empty subsets S of the elements
+        let mask = usize::from(elements);
+        let mut subset = (mask - 1) & mask;
+        let mut volume = 0;
+        while subset != 0 {
+            let s = Set::from(subset);
+            subset = (subset - 1) & mask;
+
+            // the lattice distance from the vertex of the basis to the face x(S) = r(S)
+            let height = (rank(&s) - basis.intersect(&s).size()) as u64;
+            let restriction = (contract, contract.union(&s));
+            let contraction = (contract.union(&s), ground);
+            if height == 0
+                || minor_components(matroid, restriction.0, restriction.1).len() != 1
+                || minor_components(matroid, contraction.0, contraction.1).len() != 1
+            {
+                continue;
+            }
+
+            let facet = binomial(elements.size() - 2, s.size() - 1) as u64
+                * polytope_volume(matroid, restriction.0, restriction.1, memo)
+                * polytope_volume(matroid, contraction.0, contraction.1, memo);
+            volume += height * facet;
+        }
+        volume
+    };
+
+    memo.insert((contract, ground), volume);
+    volume
+}
+
+/// Partitions the elements 0..n into classes, where two elements are in the same class if they
+/// are in a common circuit. The classes are ordered by their smallest element.
+fn components_by_circuits(n: usize, circuits: &[Set]) -> Vec<Set> {
+    let mut components: Vec<Set> = Vec::new();
+    let mut covered = Set::empty();
+
+    for e in 0..n {
+        if covered.contains_element(e) {
+            continue;
+        }
+
+        // grow the component of e until no circuit adds any new elements
+        let mut component = Set::empty().add_element(e);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for circuit in circuits {
+                if !circuit.intersect(&component).is_empty()
+                    && !circuit.difference(&component).is_empty()
+                {
+                    component = component.union(circuit);
+                    changed = true;
+                }
+            }
+        }
+
+        covered = covered.union(&component);
+        components.push(component);
+    }
+
+    components
+}
+
+/// Try to extend the partial isomorphism given by image (element i of a is mapped to image[i] in
+/// b) to a full isomorphism.
+fn extend_isomorphism<A: Matroid + ?Sized, B: Matroid>(
+    a: &A,
+    b: &B,
+    image: &mut Vec<usize>,
+) -> bool {
+    let i = image.len();
+    if i == a.n() {
+        return true;
+    }
+
+    for candidate in 0..b.n() {
+        if image.contains(&candidate) {
+            continue;
+        }
+        image.push(candidate);
+
+        // every subset containing the new element must have the same rank as its image
+        let preserves_rank = SetIterator::new(i).all(|subset| {
+            let subset = subset.add_element(i);
+            let mapped = subset
+                .bits()
+                .fold(Set::empty(), |acc, e| acc.add_element(image[e]));
+            a.rank(&subset) == b.rank(&mapped)
+        });
+
+        if preserves_rank && extend_isomorphism(a, b, image) {
+            return true;
+        }
+        image.pop();
+    }
+
+    false
+}
+
 /// Load a matroid from a file
 /// automatically adds the extension .matroid to the path
 #[allow(unused)]
@@ -281,73 +1998,407 @@ pub fn load_matroid(path: &Path) -> Result<BasesMatroid, Box<dyn Error>> {
     Ok(storage_matroid.into())
 }
 
+/// Load a matroid from a file, as the type it was stored as (see [`LoadedMatroid`]).
+/// automatically adds the extension .matroid to the path
+pub fn load_typed_matroid(path: &Path) -> Result<LoadedMatroid, Box<dyn Error>> {
+    let storage_matroid = StoredMatroidKind::from_file(path)?;
+    Ok(storage_matroid.into())
+}
+
+/// Read a matroid in the given format from a reader
+pub fn read_matroid<R: Read>(
+    reader: &mut R,
+    format: StorageFormat,
+) -> Result<LoadedMatroid, StorageError> {
+    Ok(StoredMatroidKind::read_from(reader, format)?.into())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
-    use super::super::MatrixMatroid;
+    use super::super::MatrixMatroid;
+
+    use crate::matrix::DynMatrix;
+    use crate::matroid::{GraphicMatroid, UniformMatroid};
+
+    use tinyfield::prime_field::PrimeField;
+    use tinyfield::GF2;
+
+    use std::collections::HashMap;
+    use std::env::temp_dir;
+    use uuid::Uuid;
+    #[test]
+    fn equiality() {
+        let one = GF2::one;
+
+        let umatrix = DynMatrix::from_rows(&[&[one, one, one, one]]).unwrap();
+        let uniform_from_matrix = MatrixMatroid::from(umatrix);
+
+        let u14 = UniformMatroid::new(1, 4);
+
+        assert!(u14.is_equal(&uniform_from_matrix));
+    }
+
+    #[test]
+    fn equality_fast_rejection() {
+        let m1 = crate::matroid::examples::matroid_1();
+        let m2 = crate::matroid::examples::matroid_2();
+
+        // record the largest subset the rank of m2 is asked for
+        let largest = std::cell::Cell::new(0);
+        let observed = FromRankFn {
+            n: m2.n(),
+            k: m2.k(),
+            rank: |s: &Set| {
+                largest.set(largest.get().max(s.size()));
+                m2.rank(s)
+            },
+        };
+
+        assert!(!m1.is_equal(&observed));
+        // only the bases were needed to tell them apart
+        assert_eq!(largest.get(), m2.k());
+
+        let u36 = UniformMatroid::new(3, 6);
+        assert!(u36.is_equal(&u36));
+        assert!(u36.is_equal(&BasesMatroid::new(u36.bases(), 6, 3)));
+        assert!(!u36.is_equal(&BasesMatroid::new(u36.bases()[1..].to_vec(), 6, 3)));
+    }
+
+    #[test]
+    fn storage() {
+        let mut path = temp_dir();
+        path.push(Uuid::new_v4().to_string());
+        let matroid = UniformMatroid::new(3, 6);
+
+        matroid.save(&path).unwrap();
+
+        let loaded = load_matroid(&path).unwrap();
+
+        let original_independents = matroid.independents();
+        let loaded_independents = loaded.independents();
+
+        assert_eq!(original_independents, loaded_independents);
+    }
+
+    #[test]
+    fn uniformity() {
+        let u37 = UniformMatroid::new(3, 7);
+        let m = crate::matroid::examples::matroid_1();
+
+        assert!(u37.is_uniform());
+        assert!(!m.is_uniform());
+    }
+
+    #[test]
+    fn restrict() {
+        let u36 = UniformMatroid::new(3, 6);
+        let u34 = UniformMatroid::new(3, 4);
+        let u33 = UniformMatroid::new(3, 3);
+        let u22 = UniformMatroid::new(2, 2);
+
+        let restricted = u36.restrict(&0b111010.into());
+        assert!(restricted.is_equal(&u34));
+
+        let restricted = u36.restrict(&0b101001.into());
+        assert!(restricted.is_equal(&u33));
+
+        let restricted = u36.restrict(&0b001010.into());
+        assert!(restricted.is_equal(&u22));
+    }
+
+    #[test]
+    fn minor() {
+        let u36 = UniformMatroid::new(3, 6);
+
+        // deleting and contracting one element each of U(3, 6) gives U(2, 4)
+        let minor = u36.minor(&0b000100.into(), &0b100000.into());
+        assert!(minor.is_equal(&UniformMatroid::new(2, 4)));
+
+        // contracting a circuit of size 2 gives a loop on the other parallel element
+        let one = GF2::one;
+        let zero = GF2::zero;
+        let matrix =
+            DynMatrix::from_rows(&[&[one, zero, one, one], &[zero, one, one, one]]).unwrap();
+        let matroid = MatrixMatroid::from(matrix);
+        let minor = matroid.minor(&Set::empty(), &0b0001.into());
+        assert_eq!((minor.n(), minor.k()), (3, 1));
+        assert_eq!(minor.rank(&0b001.into()), 1);
+        assert_eq!(minor.rank(&0b110.into()), 1);
+        assert!(minor.is_circuit(&0b110.into()));
+    }
+
+    #[test]
+    fn delete_contract() {
+        let u36 = UniformMatroid::new(3, 6);
+        let (deletion, contraction) = u36.delete_contract(2);
+
+        assert!(deletion.is_equal(&UniformMatroid::new(3, 5)));
+        assert!(contraction.is_equal(&UniformMatroid::new(2, 5)));
+
+        // the same as the general minors
+        let m = crate::matroid::examples::matroid_1();
+        for e in 0..m.n() {
+            let (deletion, contraction) = m.delete_contract(e);
+            let single = Set::empty().add_element(e);
+            assert!(deletion.is_equal(&m.minor(&single, &Set::empty())));
+            assert!(contraction.is_equal(&m.minor(&Set::empty(), &single)));
+        }
+
+        // a coloop is contracted when deleted, and a loop is deleted when contracted
+        let u22 = UniformMatroid::new(2, 2);
+        let (deletion, contraction) = u22.delete_contract(0);
+        assert!(deletion.is_equal(&contraction));
+        let u02 = UniformMatroid::new(0, 2);
+        let (deletion, contraction) = u02.delete_contract(1);
+        assert!(deletion.is_equal(&contraction));
+    }
+
+    #[test]
+    fn circuits_after_deleting() {
+        let u36 = UniformMatroid::new(3, 6);
+        let deleted = u36.minor(&0b000001.into(), &Set::empty());
 
-    use crate::matrix::DynMatrix;
-    use crate::matroid::UniformMatroid;
+        assert_eq!(u36.circuits_after_deleting(0), deleted.circuits());
 
-    use tinyfield::prime_field::PrimeField;
-    use tinyfield::GF2;
+        let m = crate::matroid::examples::matroid_1();
+        for e in 0..m.n() {
+            let (deletion, _) = m.delete_contract(e);
+            assert_eq!(m.circuits_after_deleting(e), deletion.circuits());
+        }
+    }
 
-    use std::collections::HashMap;
-    use std::env::temp_dir;
-    use uuid::Uuid;
     #[test]
-    fn equiality() {
+    fn isomorphism() {
+        let fano = crate::matroid::examples::fano();
+        // the columns of this matrix are the points of the Fano plane in another order
         let one = GF2::one;
+        let zero = GF2::zero;
+        let matrix = DynMatrix::from_rows(&[
+            &[one, zero, zero, one, one, zero, one],
+            &[zero, one, zero, one, zero, one, one],
+            &[zero, zero, one, zero, one, one, one],
+        ])
+        .unwrap();
+        let binary = MatrixMatroid::from(matrix);
+
+        assert!(!fano.is_equal(&binary));
+        assert!(fano.is_isomorphic(&binary));
+        assert!(!fano.is_isomorphic(&UniformMatroid::new(3, 7)));
+
+        let m1 = crate::matroid::examples::matroid_1();
+        let m2 = crate::matroid::examples::matroid_2();
+        assert!(!m1.is_isomorphic(&m2));
+    }
 
-        let umatrix = DynMatrix::from_rows(&[&[one, one, one, one]]).unwrap();
-        let uniform_from_matrix = MatrixMatroid::from(umatrix);
-
-        let u14 = UniformMatroid::new(1, 4);
+    #[test]
+    fn quotients() {
+        let u24 = UniformMatroid::new(2, 4);
+        let u34 = UniformMatroid::new(3, 4);
+        assert!(u24.is_quotient_of(&u34));
+        assert!(!u34.is_quotient_of(&u24));
+        assert!(u24.is_weak_map_image_of(&u34));
+        assert!(!u34.is_weak_map_image_of(&u24));
+        assert!(!u24.is_quotient_of(&UniformMatroid::new(3, 5)));
+
+        // the lines of the Fano plane are not flats of U(3, 7), but U(3, 7) has every
+        // independent set of the Fano plane
+        let fano = crate::matroid::examples::fano();
+        let u37 = UniformMatroid::new(3, 7);
+        assert!(!fano.is_quotient_of(&u37));
+        assert!(fano.is_weak_map_image_of(&u37));
 
-        assert!(u14.is_equal(&uniform_from_matrix));
+        // the truncation is a quotient
+        let truncation = fano.higgs_family().nth(2).unwrap();
+        assert!(truncation.is_quotient_of(&fano));
+        assert!(fano.is_quotient_of(&fano));
     }
 
     #[test]
-    fn storage() {
-        let mut path = temp_dir();
-        path.push(Uuid::new_v4().to_string());
-        let matroid = UniformMatroid::new(3, 6);
-
-        matroid.save(&path).unwrap();
+    fn has_minor() {
+        let u36 = UniformMatroid::new(3, 6);
 
-        let loaded = load_matroid(&path).unwrap();
+        assert!(u36.has_minor(&UniformMatroid::new(2, 4)));
+        assert!(u36.has_minor(&UniformMatroid::new(3, 5)));
+        assert!(u36.has_minor(&UniformMatroid::new(2, 5)));
+        assert!(!u36.has_minor(&UniformMatroid::new(1, 5)));
+        assert!(!UniformMatroid::new(2, 4).has_minor(&u36));
+        assert!(!crate::matroid::examples::fano().has_minor(&UniformMatroid::new(2, 4)));
+        // only three parallel classes, so no four point line
+        assert!(!crate::matroid::examples::non_fast_matroid().has_minor(&UniformMatroid::new(2, 4)));
+    }
 
-        let original_independents = matroid.independents();
-        let loaded_independents = loaded.independents();
+    #[test]
+    fn minor_witness() {
+        // U(2, 3) has rank one less and three elements fewer than the Fano plane
+        let fano = crate::matroid::examples::fano();
+        let u23 = UniformMatroid::new(2, 3);
+        let (delete, contract) = fano.minor_witness(&u23).unwrap();
+        assert_eq!(contract.size(), 1);
+        assert_eq!(delete.size(), 3);
+        assert!(delete.intersect(&contract).is_empty());
+        assert!(fano.minor(&delete, &contract).is_isomorphic(&u23));
+
+        assert_eq!(fano.minor_witness(&UniformMatroid::new(2, 4)), None);
+    }
 
-        assert_eq!(original_independents, loaded_independents);
+    #[test]
+    fn graph_representation() {
+        // the matroid of a triangle
+        let (vertices, edges) = UniformMatroid::new(2, 3).graph_representation().unwrap();
+        assert_eq!(vertices, 3);
+        assert_eq!(edges.len(), 3);
+        let mut degrees = vec![0; vertices];
+        for &(u, v) in edges.iter() {
+            assert_ne!(u, v);
+            degrees[u] += 1;
+            degrees[v] += 1;
+        }
+        assert_eq!(degrees, vec![2, 2, 2]);
+
+        // the triangle with every edge doubled
+        let m = crate::matroid::examples::non_fast_matroid();
+        let (vertices, edges) = m.graph_representation().unwrap();
+        assert!(GraphicMatroid::new(vertices, &edges).unwrap().is_equal(&m));
+
+        // not binary, and binary but not graphic
+        assert!(UniformMatroid::new(2, 4).graph_representation().is_none());
+        assert!(crate::matroid::examples::fano()
+            .graph_representation()
+            .is_none());
     }
 
     #[test]
-    fn uniformity() {
-        let u37 = UniformMatroid::new(3, 7);
-        let m = crate::matroid::examples::matroid_1();
+    fn graphic() {
+        let k4 = GraphicMatroid::new(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]).unwrap();
+        assert!(k4.is_graphic());
+        assert!(UniformMatroid::new(3, 3).is_graphic());
+        assert!(!UniformMatroid::new(2, 4).is_graphic());
+        assert!(!crate::matroid::examples::fano().is_graphic());
+
+        // K_3,3 is not planar, so its cycle matroid is graphic but the dual is not
+        let k33: Vec<(usize, usize)> = (0..3).flat_map(|u| (3..6).map(move |v| (u, v))).collect();
+        let k33 = GraphicMatroid::new(6, &k33).unwrap();
+        assert!(k33.is_graphic());
+        assert!(!k33.dual().is_graphic());
+        assert!(!k33.is_cographic());
+    }
 
-        assert!(u37.is_uniform());
-        assert!(!m.is_uniform());
+    #[test]
+    fn transversal() {
+        let u24 = UniformMatroid::new(2, 4);
+        assert_eq!(
+            u24.transversal_presentation(),
+            Some(vec![Set::of_size(4); 2])
+        );
+
+        // two sets sharing an element, where the presentation is already maximal
+        let sets = vec![0b00111.into(), 0b11100.into()];
+        let matroid = crate::matroid::TransversalMatroid::new(5, sets).unwrap();
+        let presentation = matroid.transversal_presentation().unwrap();
+        assert_eq!(presentation.len(), 2);
+        assert!(presentation.contains(&0b00111.into()));
+        assert!(presentation.contains(&0b11100.into()));
+
+        // a coloop, and the elements 0 and 1 in parallel
+        let bases = vec![0b101.into(), 0b110.into()];
+        assert!(BasesMatroid::new(bases, 3, 2).is_transversal());
+
+        // neither the Fano plane nor M(K_4) is transversal
+        assert!(!crate::matroid::examples::fano().is_transversal());
+        let k4 = GraphicMatroid::new(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]).unwrap();
+        assert!(!k4.is_transversal());
     }
 
     #[test]
-    fn restrict() {
-        let u36 = UniformMatroid::new(3, 6);
-        let u34 = UniformMatroid::new(3, 4);
-        let u33 = UniformMatroid::new(3, 3);
-        let u22 = UniformMatroid::new(2, 2);
+    fn minor_closure() {
+        let u23 = UniformMatroid::new(2, 3);
+        let minors = u23.minor_closure();
+
+        let contains = |m: &UniformMatroid| minors.iter().any(|minor| minor.is_isomorphic(m));
+        assert!(contains(&UniformMatroid::new(2, 3)));
+        assert!(contains(&UniformMatroid::new(1, 2)));
+        assert!(contains(&UniformMatroid::new(0, 1)));
+        assert!(contains(&UniformMatroid::new(1, 1)));
+        assert!(contains(&UniformMatroid::new(0, 0)));
+
+        // U(2, 3), U(2, 2), U(1, 2), U(1, 1), U(0, 1) and U(0, 0)
+        assert_eq!(minors.len(), 6);
+
+        // no two of the minors are isomorphic
+        for (i, a) in minors.iter().enumerate() {
+            for b in minors.iter().skip(i + 1) {
+                assert!(!a.is_isomorphic(b));
+            }
+        }
+    }
 
-        let restricted = u36.restrict(&0b111010.into());
-        assert!(restricted.is_equal(&u34));
+    #[test]
+    fn ternary() {
+        assert!(UniformMatroid::new(2, 4).is_ternary());
+        assert!(!UniformMatroid::new(2, 5).is_ternary());
+        assert!(!UniformMatroid::new(3, 6).is_ternary());
+        assert!(!crate::matroid::examples::fano().is_ternary());
+        assert!(crate::matroid::examples::non_fast_matroid().is_ternary());
+    }
 
-        let restricted = u36.restrict(&0b101001.into());
-        assert!(restricted.is_equal(&u33));
+    #[test]
+    fn binary_and_regular() {
+        let fano = crate::matroid::examples::fano();
+        assert!(fano.is_binary());
+        assert!(!fano.is_regular());
+        assert!(!fano.dual().is_regular());
+        assert!(!UniformMatroid::new(2, 4).is_binary());
+        assert!(!UniformMatroid::new(3, 6).is_binary());
+
+        // graphic matroids are regular
+        assert!(UniformMatroid::new(2, 3).is_regular());
+        let k4 = GraphicMatroid::new(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]).unwrap();
+        assert!(k4.is_regular());
+        assert!(k4.is_ternary());
+
+        // AG(3, 2) is binary, but has F_7^* as a minor
+        let ag32 = crate::matroid::examples::ag32();
+        assert!(ag32.is_binary());
+        assert!(!ag32.is_regular());
+    }
 
-        let restricted = u36.restrict(&0b001010.into());
-        assert!(restricted.is_equal(&u22));
+    #[test]
+    fn cographic() {
+        assert!(UniformMatroid::new(2, 3).is_cographic());
+        assert!(UniformMatroid::new(1, 4).is_cographic());
+        assert!(!UniformMatroid::new(2, 4).is_cographic());
+
+        // the cycle matroid of a planar graph is cographic
+        let k4 = GraphicMatroid::new(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]).unwrap();
+        assert!(k4.is_cographic());
+        let fano = crate::matroid::examples::fano();
+        assert!(!fano.is_cographic());
+
+        // only U(2, 4) is excluded for binary matroids
+        let excluded = [UniformMatroid::new(2, 4).to_bases_matroid()];
+        assert!(fano.avoids_minors(&excluded));
+        assert!(!UniformMatroid::new(3, 5).avoids_minors(&excluded));
+        assert!(fano.avoids_minors(&[]));
+        assert!(fano.is_binary_via_minors());
+        assert!(k4.is_binary_via_minors());
+        assert!(!UniformMatroid::new(3, 6).is_binary_via_minors());
+
+        // the ternary matroids have none of these minors
+        let excluded = [
+            UniformMatroid::new(2, 5).to_bases_matroid(),
+            UniformMatroid::new(3, 5).to_bases_matroid(),
+            fano.to_bases_matroid(),
+            fano.dual().to_bases_matroid(),
+        ];
+        for m in [
+            crate::matroid::examples::non_fast_matroid(),
+            crate::matroid::examples::r8(),
+            crate::matroid::examples::ag32(),
+        ] {
+            assert_eq!(m.avoids_minors(&excluded), m.is_ternary());
+        }
     }
 
     #[test]
@@ -406,6 +2457,886 @@ mod test {
         assert_eq!(v, u25.betti().betti_numbers());
     }
 
+    #[test]
+    fn dual_betti_self_dual() {
+        let u36 = UniformMatroid::new(3, 6);
+
+        assert_eq!(
+            u36.dual_betti().betti_numbers(),
+            u36.betti().betti_numbers()
+        );
+    }
+
+    #[test]
+    fn dual_betti_alexander_duality() {
+        let u25 = UniformMatroid::new(2, 5);
+        let u35 = UniformMatroid::new(3, 5);
+
+        assert_eq!(
+            u25.dual_betti().betti_numbers(),
+            u35.betti().betti_numbers()
+        );
+        assert_eq!(
+            u35.dual_betti().betti_numbers(),
+            u25.betti().betti_numbers()
+        );
+
+        // b_{i,j}(M*) = sum of |μ(F, E)| over flats F of M with rank k - i and cardinality n - j
+        fn from_flats<M: Matroid>(m: &M) -> Vec<(usize, usize, usize)> {
+            let flats: Vec<Set> = SetIterator::new(m.n())
+                .filter(|f| {
+                    let r = m.rank(f);
+                    (0..m.n())
+                        .filter(|e| !f.contains_element(*e))
+                        .all(|e| m.rank(&f.add_element(e)) > r)
+                })
+                .collect();
+
+            // μ(F, E), calculated from the top of the lattice and down
+            let mut mobius: HashMap<Set, i64> = HashMap::new();
+            let mut sorted = flats.clone();
+            sorted.sort_by_key(|f| std::cmp::Reverse(f.size()));
+            for f in sorted {
+                let mu = -mobius
+                    .iter()
+                    .filter(|(g, _)| &f < *g)
+                    .map(|(_, mu)| mu)
+                    .sum::<i64>();
+                mobius.insert(f, if mobius.is_empty() { 1 } else { mu });
+            }
+
+            let mut betti = HashMap::<(usize, usize), usize>::new();
+            for (f, mu) in mobius {
+                let key = (m.k() - m.rank(&f), m.n() - f.size());
+                *betti.entry(key).or_insert(0) += mu.unsigned_abs() as usize;
+            }
+            let mut v: Vec<_> = betti.into_iter().map(|((i, j), b)| (i, j, b)).collect();
+            v.sort();
+            v
+        }
+
+        assert_eq!(from_flats(&u25), u25.dual_betti().betti_numbers());
+        assert_eq!(from_flats(&u35), u35.dual_betti().betti_numbers());
+
+        let m = crate::matroid::examples::non_fast_matroid();
+        assert_eq!(from_flats(&m), m.dual_betti().betti_numbers());
+    }
+
+    #[test]
+    fn loops_and_coloops() {
+        let u36 = UniformMatroid::new(3, 6);
+        assert_eq!((u36.loops(), u36.coloops()), (Set::empty(), Set::empty()));
+
+        let free = UniformMatroid::new(4, 4);
+        assert_eq!(free.coloops(), Set::of_size(4));
+        assert_eq!(UniformMatroid::new(0, 3).loops(), Set::of_size(3));
+
+        // 0 and 1 are parallel, 2 is a loop and 3 is a coloop
+        let bases = vec![0b1001.into(), 0b1010.into()];
+        let m = BasesMatroid::new(bases, 4, 2);
+        assert_eq!(m.loops(), Set::from(0b0100));
+        assert_eq!(m.coloops(), Set::from(0b1000));
+        assert_eq!(m.dual().loops(), m.coloops());
+    }
+
+    #[test]
+    fn circumference() {
+        let u24 = UniformMatroid::new(2, 4);
+        assert_eq!(u24.circumference(), Some(3));
+        assert_eq!(u24.cocircumference(), Some(3));
+
+        // the largest circuits of the Fano plane are the complements of the lines
+        let fano = crate::matroid::examples::fano();
+        assert_eq!(fano.circumference(), Some(4));
+        assert_eq!(fano.cocircumference(), Some(4));
+
+        let free = UniformMatroid::new(3, 3);
+        assert_eq!(free.circumference(), None);
+        assert_eq!(free.cocircumference(), Some(1));
+        let loops = UniformMatroid::new(0, 2);
+        assert_eq!(loops.circumference(), Some(1));
+        assert_eq!(loops.cocircumference(), None);
+    }
+
+    #[test]
+    fn modular_flats() {
+        // the lattice of flats of a projective plane is modular
+        let fano = crate::matroid::examples::fano();
+        assert_eq!(fano.modular_flats().len(), 16);
+        // pairs of points, pairs of lines, and points outside lines
+        assert_eq!(fano.modular_pairs().len(), 21 + 21 + 28);
+        assert!(fano.is_modular_flat(&0b0000111.into()));
+        assert!(!fano.is_modular_flat(&0b0000011.into()));
+
+        // in U(3, 4), two disjoint lines do not meet, so only the points are modular
+        let u34 = UniformMatroid::new(3, 4);
+        let modular = u34.modular_flats();
+        assert_eq!(modular.len(), 6);
+        assert!(modular.iter().all(|f| f.size() != 2));
+        assert!(!u34.is_modular_flat(&0b0011.into()));
+        assert_eq!(u34.modular_pairs().len(), 30);
+        assert!(!u34
+            .modular_pairs()
+            .contains(&(0b0011.into(), 0b1100.into())));
+    }
+
+    #[test]
+    fn cocircuits() {
+        let u36 = UniformMatroid::new(3, 6);
+        let cocircuits = u36.cocircuits();
+
+        assert_eq!(cocircuits.len(), 15);
+        assert!(cocircuits.iter().all(|c| c.size() == 6 - 3 + 1));
+
+        for m in [
+            crate::matroid::examples::matroid_1(),
+            crate::matroid::examples::non_fast_matroid(),
+        ] {
+            let cocircuits = m.cocircuits();
+            assert!(SetIterator::new(m.n()).all(|s| m.is_cocircuit(&s) == cocircuits.contains(&s)));
+            assert_eq!(cocircuits, m.dual().circuits());
+
+            let mut parallel = m.par_cocircuits();
+            parallel.sort_by_key(|c| (c.size(), usize::from(*c)));
+            let mut sequential = cocircuits;
+            sequential.sort_by_key(|c| (c.size(), usize::from(*c)));
+            assert_eq!(parallel, sequential);
+        }
+    }
+
+    #[test]
+    fn girth() {
+        let u36 = UniformMatroid::new(3, 6);
+        assert_eq!(u36.girth(), Some(4));
+        assert_eq!(u36.cogirth(), Some(4));
+
+        let u25 = UniformMatroid::new(2, 5);
+        assert_eq!(u25.girth(), Some(3));
+        assert_eq!(u25.cogirth(), Some(4));
+        assert_eq!(u25.dual().girth(), u25.cogirth());
+
+        let free = UniformMatroid::new(4, 4);
+        assert_eq!(free.girth(), None);
+        assert_eq!(free.cogirth(), Some(1));
+        assert_eq!(UniformMatroid::new(0, 3).cogirth(), None);
+    }
+
+    #[test]
+    fn free_matroid() {
+        use crate::betti_nums::BettiNumbers;
+
+        let free = UniformMatroid::new(4, 4);
+        assert!(free.circuits().is_empty());
+        assert_eq!(free.girth(), None);
+        assert_eq!(free.connectivity_parameters(), (None, Some(1), false));
+
+        assert_eq!(BettiNumbers::new(&free).betti_numbers(), vec![(0, 0, 1)]);
+        assert_eq!(
+            BettiNumbers::new_direct(&free).betti_numbers(),
+            vec![(0, 0, 1)]
+        );
+
+        let derived = free.combinatorial_derived();
+        assert_eq!((derived.n(), derived.k()), (0, 0));
+
+        // the tutte polynomial of a free matroid is x^n
+        let tutte = free.tutte_polynomial();
+        assert_eq!(tutte[4], vec![1]);
+        assert!(tutte[..4].iter().flatten().all(|&c| c == 0));
+    }
+
+    #[test]
+    fn sorted_bases() {
+        let u24 = UniformMatroid::new(2, 4);
+        let sorted = u24.sorted_bases();
+
+        assert_eq!(sorted.len(), 6);
+        assert!(sorted
+            .windows(2)
+            .all(|w| usize::from(w[0]) < usize::from(w[1])));
+        assert_eq!(sorted, u24.sorted_bases());
+
+        // the order does not depend on the order of the bases given to a bases matroid
+        let reversed = BasesMatroid::new(sorted.iter().rev().cloned().collect(), 4, 2);
+        assert_eq!(reversed.sorted_bases(), sorted);
+    }
+
+    #[test]
+    fn components() {
+        // the direct sum of two copies of U(1, 2), with the elements {0, 2} and {1, 3} parallel
+        let bases = vec![0b0011.into(), 0b0110.into(), 0b1001.into(), 0b1100.into()];
+        let sum = BasesMatroid::new(bases, 4, 2);
+
+        assert_eq!(
+            sum.connected_components(),
+            vec![Set::from(0b0101), Set::from(0b1010)]
+        );
+
+        let components = sum.components();
+        assert_eq!(components.len(), 2);
+        let u12 = UniformMatroid::new(1, 2);
+        assert!(components.iter().all(|c| c.is_equal(&u12)));
+
+        // putting the components back together gives the original matroid, up to relabeling
+        let (a, b) = (&components[0], &components[1]);
+        let recombined = BasesMatroid::new(
+            a.bases()
+                .iter()
+                .flat_map(|x| {
+                    b.bases()
+                        .into_iter()
+                        .map(move |y| Set::from(usize::from(*x) | usize::from(y) << a.n()))
+                })
+                .collect(),
+            a.n() + b.n(),
+            a.k() + b.k(),
+        );
+        assert!(recombined.is_isomorphic(&sum));
+
+        let u36 = UniformMatroid::new(3, 6);
+        assert_eq!(u36.connected_components(), vec![Set::of_size(6)]);
+        // every element of a free matroid is a component
+        assert_eq!(UniformMatroid::new(3, 3).components().len(), 3);
+    }
+
+    #[test]
+    fn connectivity_parameters() {
+        let u36 = UniformMatroid::new(3, 6);
+        assert_eq!(u36.connectivity_parameters(), (Some(4), Some(4), true));
+
+        // the direct sum of two copies of U(1, 2)
+        let bases = vec![0b0101.into(), 0b1001.into(), 0b0110.into(), 0b1010.into()];
+        let sum = BasesMatroid::new(bases, 4, 2);
+        assert_eq!(sum.connectivity_parameters(), (Some(2), Some(2), false));
+
+        // every element is a coloop
+        let u22 = UniformMatroid::new(2, 2);
+        assert_eq!(u22.connectivity_parameters(), (None, Some(1), false));
+
+        assert!(crate::matroid::examples::non_fast_matroid().is_connected());
+        assert!(crate::matroid::examples::fano().is_connected());
+    }
+
+    #[test]
+    fn k_connectivity() {
+        let fano = crate::matroid::examples::fano();
+        // a line of the Fano plane and the four points outside it
+        let line = Set::from(0b0000111);
+        assert_eq!(fano.connectivity(&line), 2);
+        assert_eq!(fano.connectivity(&line), fano.dual().connectivity(&line));
+        assert!(fano.is_k_connected(3));
+        assert!(!fano.is_k_connected(4));
+
+        // three points of U(2, 6) and the other three give a 3-separation
+        let u26 = UniformMatroid::new(2, 6);
+        assert!(u26.is_k_connected(3));
+        assert!(!u26.is_k_connected(4));
+        // U(3, 6) has no separations at all
+        assert!(UniformMatroid::new(3, 6).is_k_connected(7));
+
+        // the direct sum of two copies of U(1, 2) has a 1-separation
+        let bases = vec![0b0101.into(), 0b1001.into(), 0b0110.into(), 0b1010.into()];
+        let sum = BasesMatroid::new(bases, 4, 2);
+        assert!(sum.is_k_connected(1));
+        assert!(!sum.is_k_connected(2));
+        assert_eq!(sum.is_k_connected(2), sum.is_connected());
+    }
+
+    #[test]
+    fn three_connected() {
+        let fano = crate::matroid::examples::fano();
+        assert_eq!(fano.three_connected(), Ok(()));
+        // a line has λ = 2, but rank 2, so it is not a vertical 3-separation
+        assert_eq!(fano.vertical_connectivity(), 3);
+
+        // two triangles sharing an edge, where the shared edge separates the graph
+        let triangle = UniformMatroid::new(2, 3);
+        let m = crate::matroid::parallel_connection(&triangle, 0, &triangle, 0);
+        let (x, y) = m.three_connected().unwrap_err();
+        assert_eq!(x.union(&y), Set::of_size(5));
+        assert!(x.size() >= 2 && y.size() >= 2);
+        assert!(m.connectivity(&x) < 2);
+        assert_eq!(m.vertical_connectivity(), 2);
+
+        // a disconnected matroid has a 1-separation
+        let bases = vec![0b0101.into(), 0b1001.into(), 0b0110.into(), 0b1010.into()];
+        let sum = BasesMatroid::new(bases, 4, 2);
+        let (x, _) = sum.three_connected().unwrap_err();
+        assert_eq!(sum.connectivity(&x), 0);
+        assert_eq!(sum.vertical_connectivity(), 1);
+    }
+
+    #[test]
+    fn fundamental_circuit_components() {
+        // a parallel pair, a loop, a coloop and a triangle
+        let bases: Vec<Set> = [0b0001001, 0b0001010]
+            .iter()
+            .flat_map(|&b| {
+                [0b011, 0b101, 0b110]
+                    .iter()
+                    .map(move |&t| Set::from(b | t << 4))
+            })
+            .collect();
+        let m = BasesMatroid::new(bases, 7, 4);
+        let expected = vec![
+            Set::from(0b0000011),
+            Set::from(0b0000100),
+            Set::from(0b0001000),
+            Set::from(0b1110000),
+        ];
+        assert_eq!(m.connected_components(), expected);
+        assert!(!m.is_connected());
+
+        for m in [
+            crate::matroid::examples::matroid_1(),
+            crate::matroid::examples::matroid_2(),
+            m,
+        ] {
+            assert_eq!(
+                m.connected_components(),
+                components_by_circuits(m.n(), &m.circuits())
+            );
+        }
+    }
+
+    #[test]
+    fn format_sets() {
+        let u23 = UniformMatroid::new(2, 3);
+        let labels = ["a", "b", "c"];
+
+        assert_eq!(u23.format_sets(&u23.circuits(), &labels), "{a,b,c}");
+
+        let mut formatted: Vec<String> = u23
+            .format_sets(&u23.bases(), &labels)
+            .split(", ")
+            .map(String::from)
+            .collect();
+        formatted.sort();
+        assert_eq!(formatted, vec!["{a,b}", "{a,c}", "{b,c}"]);
+
+        assert_eq!(u23.format_sets(&[Set::empty()], &labels), "{}");
+        assert_eq!(u23.format_sets(&[], &labels), "");
+    }
+
+    #[test]
+    #[should_panic]
+    fn format_sets_wrong_labels() {
+        UniformMatroid::new(2, 3).format_sets(&[], &["a", "b"]);
+    }
+
+    #[test]
+    fn element_basis_counts() {
+        let m = crate::matroid::examples::matroid_1();
+        let counts = m.element_basis_counts();
+
+        // the element 0 is a coloop, so it is in every basis
+        assert_eq!(counts[0], m.bases().len());
+
+        let mut sorted = counts.clone();
+        sorted.sort();
+        assert_eq!(sorted, m.bases_series());
+    }
+
+    #[test]
+    fn bases_containing() {
+        let u24 = UniformMatroid::new(2, 4);
+
+        // a fixed element is in binomial(3, 1) bases, and not in binomial(3, 2) bases
+        assert_eq!(u24.bases_containing(&0b0100.into()), binomial(3, 1));
+        assert_eq!(u24.bases_avoiding(&0b0100.into()), binomial(3, 2));
+
+        assert_eq!(u24.bases_containing(&0b0101.into()), 1);
+        assert_eq!(u24.bases_avoiding(&0b0101.into()), 1);
+
+        assert_eq!(u24.bases_containing(&Set::empty()), 6);
+        assert_eq!(u24.bases_avoiding(&Set::empty()), 6);
+        assert_eq!(u24.bases_containing(&0b0111.into()), 0);
+    }
+
+    #[test]
+    fn span_and_rank() {
+        let u36 = UniformMatroid::new(3, 6);
+
+        for subset in [0b0usize, 0b1, 0b100100, 0b10101, 0b111100, 0b111111] {
+            let subset = Set::from(subset);
+            let (span, rank) = u36.span_and_rank(&subset);
+
+            assert_eq!(rank, u36.rank(&subset));
+            assert_eq!(span, u36.closure(&subset));
+        }
+
+        assert_eq!(u36.closure(&0b100100.into()), Set::from(0b100100));
+        assert_eq!(u36.closure(&0b10101.into()), Set::of_size(6));
+    }
+
+    /// a matroid given by an arbitrary rank function, used to test the axiom checks
+    struct FromRankFn<F: Fn(&Set) -> usize> {
+        n: usize,
+        k: usize,
+        rank: F,
+    }
+
+    impl<F: Fn(&Set) -> usize> Matroid for FromRankFn<F> {
+        fn rank(&self, subset: &Set) -> usize {
+            (self.rank)(subset)
+        }
+
+        fn k(&self) -> usize {
+            self.k
+        }
+
+        fn n(&self) -> usize {
+            self.n
+        }
+    }
+
+    #[test]
+    fn rank_axioms() {
+        assert_eq!(UniformMatroid::new(2, 4).check_rank_axioms(), Ok(()));
+        assert_eq!(
+            crate::matroid::examples::matroid_1().check_rank_axioms(),
+            Ok(())
+        );
+
+        let not_monotone = FromRankFn {
+            n: 3,
+            k: 1,
+            rank: |s: &Set| if s.size() == 3 { 1 } else { s.size() },
+        };
+        assert_eq!(
+            not_monotone.check_rank_axioms(),
+            Err("r(11) = 2 is larger than r(111) = 1, even though 11 is a subset of 111".into())
+        );
+
+        let not_submodular = FromRankFn {
+            n: 2,
+            k: 1,
+            rank: |s: &Set| if s.size() == 2 { 1 } else { 0 },
+        };
+        assert_eq!(
+            not_submodular.check_rank_axioms(),
+            Err("r(1) + r(10) = 0 is smaller than r(1 ∪ 10) + r(1 ∩ 10) = 1".into())
+        );
+
+        let too_large = FromRankFn {
+            n: 2,
+            k: 2,
+            rank: |_: &Set| 2,
+        };
+        assert_eq!(
+            too_large.check_rank_axioms(),
+            Err("r(0) = 2 is larger than |0| = 0".into())
+        );
+    }
+
+    #[test]
+    fn tutte_polynomial() {
+        // T(U(2, 3)) = x^2 + x + y
+        assert_eq!(
+            UniformMatroid::new(2, 3).tutte_polynomial(),
+            vec![vec![0, 1], vec![1, 0], vec![1, 0]]
+        );
+
+        // T(U(2, 5)) = x^2 + 3x + 3y + 2y^2 + y^3
+        assert_eq!(
+            UniformMatroid::new(2, 5).tutte_polynomial(),
+            vec![vec![0, 3, 2, 1], vec![3, 0, 0, 0], vec![1, 0, 0, 0]]
+        );
+
+        // the number of bases is T(1, 1)
+        let m = crate::matroid::examples::matroid_1();
+        let bases: i64 = m.tutte_polynomial().iter().flatten().sum();
+        assert_eq!(bases, m.bases().len() as i64);
+    }
+
+    #[test]
+    fn reduced_euler_characteristic() {
+        // the independent sets are the empty set, 4 points and 6 edges: -1 + 4 - 6
+        let u24 = UniformMatroid::new(2, 4);
+        assert_eq!(u24.reduced_euler_characteristic(), -3);
+
+        for m in [
+            crate::matroid::examples::matroid_1(),
+            crate::matroid::examples::non_fast_matroid(),
+            crate::matroid::examples::fano(),
+        ] {
+            assert_eq!(m.reduced_euler_characteristic(), m.euler_characteristic());
+
+            // (-1)^(k - 1) T(0, 1)
+            let t01: i64 = m.tutte_polynomial()[0].iter().sum();
+            let sign = if m.k() % 2 == 1 { 1 } else { -1 };
+            assert_eq!(m.reduced_euler_characteristic() as i64, sign * t01);
+        }
+        assert_eq!(
+            u24.reduced_euler_characteristic(),
+            u24.euler_characteristic()
+        );
+    }
+
+    #[test]
+    fn corank_nullity_distribution() {
+        let u24 = UniformMatroid::new(2, 4);
+        let table = u24.corank_nullity_distribution();
+
+        assert_eq!(table.iter().flatten().sum::<usize>(), 1 << 4);
+        assert_eq!(table, vec![vec![6, 4, 1], vec![4, 0, 0], vec![1, 0, 0]]);
+        // only the bases have both corank and nullity 0
+        let diagonal: Vec<usize> = (0..=2).map(|i| table[i][i]).collect();
+        assert_eq!(diagonal, vec![6, 0, 0]);
+
+        let m = crate::matroid::examples::matroid_1();
+        let table = m.corank_nullity_distribution();
+        assert_eq!(table.iter().flatten().sum::<usize>(), 1 << m.n());
+        assert_eq!(table[0][0], m.bases().len());
+        // the independent sets are the subsets of nullity 0
+        let independents: usize = table.iter().map(|row| row[0]).sum();
+        assert_eq!(independents, m.independents().len());
+    }
+
+    #[test]
+    fn characteristic_polynomial() {
+        // (t - 1)(t - 2)
+        let triangle = UniformMatroid::new(2, 3);
+        assert_eq!(triangle.characteristic_polynomial(), vec![2, -3, 1]);
+
+        // the cycle matroid of K_4 has χ(t) = (t - 1)(t - 2)(t - 3)
+        let k4 = GraphicMatroid::new(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]).unwrap();
+        assert_eq!(k4.characteristic_polynomial(), vec![-6, 11, -6, 1]);
+        assert_eq!(k4.mobius_invariant(), -6);
+        assert_eq!(k4.beta_invariant(), 2);
+
+        // the Fano plane has χ(t) = (t - 1)(t - 2)(t - 4)
+        let fano = crate::matroid::examples::fano();
+        assert_eq!(fano.characteristic_polynomial(), vec![-8, 14, -7, 1]);
+        assert_eq!(fano.beta_invariant(), 3);
+
+        // χ(t) = sum over X of (-1)^|X| t^(r(E) - r(X))
+        let m = crate::matroid::examples::matroid_1();
+        let chi = m.characteristic_polynomial();
+        let mut direct = vec![0i64; m.k() + 1];
+        for s in SetIterator::new(m.n()) {
+            direct[m.k() - m.rank(&s)] += if s.size() % 2 == 0 { 1 } else { -1 };
+        }
+        assert_eq!(chi, direct);
+
+        // a matroid with a loop has χ = 0, and a disconnected matroid has β = 0
+        assert_eq!(
+            UniformMatroid::new(0, 1).characteristic_polynomial(),
+            vec![0]
+        );
+        let bases = vec![0b0101.into(), 0b1001.into(), 0b0110.into(), 0b1010.into()];
+        assert_eq!(BasesMatroid::new(bases, 4, 2).beta_invariant(), 0);
+    }
+
+    #[test]
+    fn beta_invariant() {
+        use crate::matroid::{parallel_connection, series_connection};
+
+        // series-parallel networks have β = 1
+        let triangle = UniformMatroid::new(2, 3);
+        let parallel = parallel_connection(&triangle, 0, &triangle, 0);
+        assert_eq!(parallel.beta_invariant(), 1);
+        let series = series_connection(&parallel, 4, &triangle, 2);
+        assert_eq!(series.beta_invariant(), 1);
+        assert_eq!(UniformMatroid::new(1, 4).beta_invariant(), 1);
+
+        // the same as the derivative of the characteristic polynomial
+        for m in [
+            crate::matroid::examples::matroid_1(),
+            crate::matroid::examples::non_fast_matroid(),
+            crate::matroid::examples::ag32(),
+        ] {
+            let derivative: i64 = m
+                .characteristic_polynomial()
+                .iter()
+                .enumerate()
+                .map(|(i, c)| i as i64 * c)
+                .sum();
+            let sign = if (m.k() - 1) % 2 == 0 { 1 } else { -1 };
+            assert_eq!(m.beta_invariant(), sign * derivative);
+        }
+
+        // the uniform matroid U(2, n) has β = n - 2, and a loop has β = 0
+        assert_eq!(UniformMatroid::new(2, 6).beta_invariant(), 4);
+        assert_eq!(UniformMatroid::new(0, 1).beta_invariant(), 0);
+    }
+
+    #[test]
+    fn activities() {
+        // in U(2, 3) with the basis {1, 2}, the fundamental circuit of 0 is everything, so 0 is
+        // externally active, while the fundamental cocircuits {0, 1} and {0, 2} both contain 0
+        let u23 = UniformMatroid::new(2, 3);
+        let (internal, external) = u23.activities(&Set::from(0b110), &[0, 1, 2]);
+        assert_eq!((internal, external), (Set::empty(), Set::from(0b001)));
+        let (internal, external) = u23.activities(&Set::from(0b011), &[0, 1, 2]);
+        assert_eq!((internal, external), (Set::from(0b011), Set::empty()));
+
+        // reversing the order makes 2 the smallest element
+        let (internal, external) = u23.activities(&Set::from(0b011), &[2, 1, 0]);
+        assert_eq!((internal, external), (Set::empty(), Set::from(0b100)));
+
+        for m in [
+            crate::matroid::examples::matroid_1(),
+            crate::matroid::examples::fano(),
+            UniformMatroid::new(3, 6).restrict(&Set::of_size(5)),
+        ] {
+            assert_eq!(m.tutte_polynomial_by_activities(), m.tutte_polynomial());
+        }
+    }
+
+    #[test]
+    fn chow_ring_hilbert() {
+        assert_eq!(UniformMatroid::new(2, 3).chow_ring_hilbert(), vec![1, 1]);
+        // for the boolean matroid, the dimensions are the Eulerian numbers
+        assert_eq!(UniformMatroid::new(3, 3).chow_ring_hilbert(), vec![1, 4, 1]);
+        assert_eq!(
+            UniformMatroid::new(4, 4).chow_ring_hilbert(),
+            vec![1, 11, 11, 1]
+        );
+
+        // the degree 1 part has one generator for each flat of rank at least 2
+        let fano = crate::matroid::examples::fano();
+        assert_eq!(fano.chow_ring_hilbert(), vec![1, 8, 1]);
+
+        // the dimensions are palindromic, and loops do not change the lattice of flats
+        let m = crate::matroid::examples::matroid_1();
+        let hilbert = m.chow_ring_hilbert();
+        assert_eq!(hilbert.len(), m.k());
+        assert!(hilbert.iter().eq(hilbert.iter().rev()));
+        let bases = vec![0b011.into(), 0b101.into(), 0b110.into()];
+        let with_loop = BasesMatroid::new(bases, 4, 2);
+        assert_eq!(with_loop.chow_ring_hilbert(), vec![1, 1]);
+    }
+
+    #[test]
+    fn weighted_bases() {
+        let u24 = UniformMatroid::new(2, 4);
+        let weights = [1.0, 4.0, -2.0, 3.0];
+        assert_eq!(u24.max_weight_basis(&weights), Set::from(vec![1, 3]));
+        assert_eq!(u24.min_weight_basis(&weights), Set::from(vec![0, 2]));
+
+        // a minimum spanning tree of K_4, where the three light edges form a triangle
+        let k4 = GraphicMatroid::new(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]).unwrap();
+        let weights = [1.0, 5.0, 1.0, 4.0, 1.0, 6.0];
+        let tree = k4.min_weight_basis(&weights);
+        assert_eq!(tree.size(), 3);
+        assert!(k4.is_independent(&tree));
+        assert!(tree.contains_element(3));
+        let total: f64 = tree.bits().map(|e| weights[e]).sum();
+        assert_eq!(total, 6.0);
+
+        // a loop is never chosen
+        let bases = vec![0b01.into()];
+        let with_loop = BasesMatroid::new(bases, 2, 1);
+        assert_eq!(with_loop.max_weight_basis(&[0.0, 10.0]), Set::from(0b01));
+    }
+
+    #[test]
+    fn packing_and_covering() {
+        let u24 = UniformMatroid::new(2, 4);
+        assert_eq!(u24.base_packing_number(), Some(2));
+        assert_eq!(u24.base_covering_number(), Some(2));
+
+        let u25 = UniformMatroid::new(2, 5);
+        assert_eq!(u25.base_packing_number(), Some(2));
+        assert_eq!(u25.base_covering_number(), Some(3));
+
+        // K_4 is the union of two disjoint spanning trees
+        let k4 = GraphicMatroid::new(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]).unwrap();
+        assert_eq!(k4.base_packing_number(), Some(2));
+        assert_eq!(k4.base_covering_number(), Some(2));
+
+        let fano = crate::matroid::examples::fano();
+        assert_eq!(fano.base_packing_number(), Some(2));
+        assert_eq!(fano.base_covering_number(), Some(3));
+
+        // three parallel elements and a coloop, which is in every basis
+        let bases = vec![0b1001.into(), 0b1010.into(), 0b1100.into()];
+        let with_coloop = BasesMatroid::new(bases, 4, 2);
+        assert_eq!(with_coloop.base_packing_number(), Some(1));
+        assert_eq!(with_coloop.base_covering_number(), Some(3));
+
+        let loops = UniformMatroid::new(0, 3);
+        assert_eq!(loops.base_packing_number(), None);
+        assert_eq!(loops.base_covering_number(), None);
+
+        // the min-max formulas of Tutte and Nash-Williams, and of Edmonds, over all subsets
+        fn packing_by_formula<M: Matroid>(m: &M) -> usize {
+            SetIterator::new(m.n())
+                .filter_map(|y| {
+                    let rank = m.rank(&y);
+                    (rank < m.k()).then(|| (m.n() - y.size()) / (m.k() - rank))
+                })
+                .min()
+                .unwrap()
+        }
+        fn covering_by_formula<M: Matroid>(m: &M) -> usize {
+            SetIterator::new(m.n())
+                .filter(|y| !y.is_empty())
+                .map(|y| y.size().div_ceil(m.rank(&y)))
+                .max()
+                .unwrap()
+        }
+
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        let mut rng = StdRng::seed_from_u64(5);
+        for (n, k) in [(6, 2), (7, 3), (8, 3), (8, 4)] {
+            let m = crate::matroid::random::random_bases_matroid(n, k, &mut rng);
+            assert_eq!(m.base_packing_number(), Some(packing_by_formula(&m)));
+            assert_eq!(m.base_covering_number(), Some(covering_by_formula(&m)));
+        }
+        for m in [
+            crate::matroid::examples::matroid_1(),
+            crate::matroid::examples::non_fast_matroid(),
+            crate::matroid::examples::ag32(),
+        ] {
+            assert_eq!(m.base_packing_number(), Some(packing_by_formula(&m)));
+            assert_eq!(m.base_covering_number(), Some(covering_by_formula(&m)));
+        }
+    }
+
+    #[test]
+    fn base_polytope() {
+        // the base polytope of U(1, n) is a simplex
+        let (vertices, edges) = UniformMatroid::new(1, 4).base_polytope();
+        assert_eq!(vertices.len(), 4);
+        assert_eq!(edges.len(), 6);
+        assert_eq!(vertices[0], vec![1, 0, 0, 0]);
+
+        // and the base polytope of U(2, 4) is an octahedron
+        let (vertices, edges) = UniformMatroid::new(2, 4).base_polytope();
+        assert_eq!(vertices.len(), 6);
+        assert_eq!(edges.len(), 12);
+        assert_eq!(vertices[0], vec![1, 1, 0, 0]);
+        assert!(edges.contains(&(0, 1)));
+        assert!(edges.iter().all(|&(i, j)| i < j));
+
+        // every vertex of the Fano polytope has degree 9: each of the 3 elements of a basis can
+        // be exchanged for the 4 elements outside it, except the one on the line through the
+        // other two
+        let (vertices, edges) = crate::matroid::examples::fano().base_polytope();
+        assert_eq!(vertices.len(), 28);
+        assert_eq!(edges.len(), 28 * 9 / 2);
+    }
+
+    #[test]
+    fn base_polytope_volume() {
+        // a point and a simplex
+        assert_eq!(UniformMatroid::new(3, 3).base_polytope_volume(), 1);
+        assert_eq!(UniformMatroid::new(1, 5).base_polytope_volume(), 1);
+
+        // the hypersimplices, with the Eulerian numbers as volumes
+        assert_eq!(UniformMatroid::new(2, 4).base_polytope_volume(), 4);
+        assert_eq!(UniformMatroid::new(2, 5).base_polytope_volume(), 11);
+        assert_eq!(UniformMatroid::new(3, 6).base_polytope_volume(), 66);
+
+        // U(1, 2) ⊕ U(1, 2) is a square, made of two triangles
+        let bases = vec![0b0101.into(), 0b1001.into(), 0b0110.into(), 0b1010.into()];
+        assert_eq!(BasesMatroid::new(bases, 4, 2).base_polytope_volume(), 2);
+
+        // the polytope of the dual is a reflection of the polytope
+        let fano = crate::matroid::examples::fano();
+        assert_eq!(
+            fano.base_polytope_volume(),
+            fano.dual().base_polytope_volume()
+        );
+    }
+
+    #[test]
+    fn g_invariant() {
+        let u23 = UniformMatroid::new(2, 3);
+        assert_eq!(u23.g_invariant(), BTreeMap::from([(vec![0, 1, 2], 3)]));
+
+        // every maximal chain of the boolean matroid adds one element at a time
+        let u33 = UniformMatroid::new(3, 3);
+        assert_eq!(u33.g_invariant(), BTreeMap::from([(vec![0, 1, 1, 1], 6)]));
+
+        // a point and a line through it, and every line has three points
+        let fano = crate::matroid::examples::fano();
+        assert_eq!(fano.g_invariant(), BTreeMap::from([(vec![0, 1, 2, 4], 21)]));
+
+        // U(2, 3) with a loop
+        let bases = vec![0b011.into(), 0b101.into(), 0b110.into()];
+        let with_loop = BasesMatroid::new(bases, 4, 2);
+        assert_eq!(
+            with_loop.g_invariant(),
+            BTreeMap::from([(vec![1, 1, 2], 3)])
+        );
+
+        // a line with three points in the plane, and two more points
+        let hyperplanes = [
+            0b00111, 0b01001, 0b10001, 0b01010, 0b10010, 0b01100, 0b10100, 0b11000,
+        ]
+        .map(Set::from)
+        .to_vec();
+        let matroid = crate::matroid::PavingMatroid::from_hyperplanes(3, 5, hyperplanes).unwrap();
+        assert_eq!(
+            matroid.g_invariant(),
+            BTreeMap::from([(vec![0, 1, 1, 3], 14), (vec![0, 1, 2, 2], 3)])
+        );
+    }
+
+    #[test]
+    fn kazhdan_lusztig_polynomial() {
+        // the polynomial is 1 in rank at most 2
+        assert_eq!(
+            UniformMatroid::new(1, 1).kazhdan_lusztig_polynomial(),
+            vec![1]
+        );
+        assert_eq!(
+            UniformMatroid::new(2, 5).kazhdan_lusztig_polynomial(),
+            vec![1]
+        );
+
+        // in rank 3 and 4, the linear coefficient is the number of hyperplanes minus the number
+        // of points
+        assert_eq!(
+            UniformMatroid::new(3, 4).kazhdan_lusztig_polynomial(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            UniformMatroid::new(4, 5).kazhdan_lusztig_polynomial(),
+            vec![1, 5]
+        );
+        let fano = crate::matroid::examples::fano();
+        // 7 lines and 7 points, so the linear coefficient is zero
+        assert_eq!(fano.kazhdan_lusztig_polynomial(), vec![1]);
+        let k4 = GraphicMatroid::new(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]).unwrap();
+        assert_eq!(k4.kazhdan_lusztig_polynomial(), vec![1, 1]);
+
+        // the polynomial of U(5, 6) is 1 + 9t + 5t^2
+        assert_eq!(
+            UniformMatroid::new(5, 6).kazhdan_lusztig_polynomial(),
+            vec![1, 9, 5]
+        );
+    }
+
+    #[test]
+    fn flow_polynomial() {
+        let evaluate = |p: &[i64], t: i64| p.iter().rev().fold(0, |acc, c| acc * t + c);
+
+        // the triangle is a single cycle, and has t - 1 nowhere-zero flows
+        let triangle = UniformMatroid::new(2, 3);
+        assert_eq!(triangle.flow_polynomial(), vec![-1, 1]);
+        for t in 1..6 {
+            assert_eq!(evaluate(&triangle.flow_polynomial(), t), t - 1);
+        }
+
+        // F(t) = sum over X of (-1)^|E - X| t^nullity(X)
+        let m = crate::matroid::examples::non_fast_matroid();
+        let flow = m.flow_polynomial();
+        for t in 0..6i64 {
+            let direct: i64 = SetIterator::new(m.n())
+                .map(|s| {
+                    let sign = if (m.n() - s.size()) % 2 == 0 { 1 } else { -1 };
+                    sign * t.pow((s.size() - m.rank(&s)) as u32)
+                })
+                .sum();
+            assert_eq!(evaluate(&flow, t), direct);
+        }
+    }
+
     #[test]
     fn corank() {
         let matroid = UniformMatroid::new(3, 7);
@@ -423,4 +3354,62 @@ mod test {
         assert_eq!(matroid.generalized_hamming_distance(3), Some(7));
         assert_eq!(matroid.generalized_hamming_distance(4), None);
     }
+
+    #[test]
+    fn singleton_defect() {
+        let u37 = UniformMatroid::new(3, 7);
+        assert!(u37.is_mds());
+        assert_eq!(u37.singleton_defect(1), Some(0));
+        assert_eq!(u37.singleton_defect(3), Some(0));
+        assert_eq!(u37.singleton_defect(0), None);
+        assert_eq!(u37.singleton_defect(4), None);
+
+        // the Fano plane is the [7, 3, 4] simplex code, so d_1 = 4 is one less than 5
+        let fano = crate::matroid::examples::fano();
+        assert!(!fano.is_mds());
+        assert_eq!(fano.singleton_defect(1), Some(1));
+        assert_eq!(fano.singleton_defect(3), Some(0));
+        assert!(UniformMatroid::new(0, 3).is_mds());
+    }
+
+    #[test]
+    fn wei_duality() {
+        assert_eq!(
+            UniformMatroid::new(3, 7).verify_wei_duality(),
+            Ok(vec![5, 6, 7, 4, 3, 2, 1])
+        );
+        let vamos = crate::matroid::Vamos::new();
+        assert_eq!(vamos.verify_wei_duality().map(|m| m.len()), Ok(8));
+        let fano = crate::matroid::examples::fano();
+        assert_eq!(fano.verify_wei_duality(), Ok(vec![4, 6, 7, 5, 3, 2, 1]));
+
+        // every element is a loop, but every pair has rank 2
+        let not_submodular = FromRankFn {
+            n: 3,
+            k: 2,
+            rank: |s: &Set| if s.size() >= 2 { 2 } else { 0 },
+        };
+        let violation = not_submodular.verify_wei_duality().unwrap_err();
+        assert_eq!(
+            violation,
+            Violation::WeiDualityMissing {
+                missing: 1,
+                merged: vec![2, 2, 3]
+            }
+        );
+        assert_eq!(violation.to_string(), "1 is missing from [2, 2, 3]");
+    }
+
+    #[test]
+    fn weight_hierarchy() {
+        assert_eq!(UniformMatroid::new(3, 7).weight_hierarchy(), vec![5, 6, 7]);
+
+        let vamos = crate::matroid::Vamos::new();
+        let dual = vamos.dual();
+        let per_h: Vec<usize> = (1..=dual.k())
+            .map(|h| dual.generalized_hamming_distance(h).unwrap())
+            .collect();
+
+        assert_eq!(dual.weight_hierarchy(), per_h);
+    }
 }
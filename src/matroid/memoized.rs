@@ -0,0 +1,103 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use dashmap::DashMap;
+
+use crate::set::Set;
+
+use super::Matroid;
+
+/// A matroid that caches the rank of every subset it has been asked about.
+///
+/// The cache is a concurrent map, so the wrapper is `Sync` whenever the wrapped matroid is, and
+/// may be used in the parallel calculations (for instance the betti numbers).
+#[derive(Debug)]
+pub struct Memoized<M: Matroid> {
+    matroid: M,
+    cache: DashMap<Set, usize>,
+    hits: AtomicUsize,
+}
+
+impl<M: Matroid> Memoized<M> {
+    /// Wrap the matroid with a rank cache
+    pub fn new(matroid: M) -> Self {
+        Self {
+            matroid,
+            cache: DashMap::new(),
+            hits: AtomicUsize::new(0),
+        }
+    }
+
+    /// The number of rank queries that were answered from the cache
+    pub fn cache_hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// The number of subsets that currently have their rank cached
+    pub fn cache_len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Remove the cache, and return the wrapped matroid
+    pub fn into_inner(self) -> M {
+        self.matroid
+    }
+}
+
+impl<M: Matroid> From<M> for Memoized<M> {
+    fn from(matroid: M) -> Self {
+        Self::new(matroid)
+    }
+}
+
+impl<M: Matroid> Matroid for Memoized<M> {
+    fn rank(&self, subset: &Set) -> usize {
+        if let Some(rank) = self.cache.get(subset) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return *rank;
+        }
+
+        let rank = self.matroid.rank(subset);
+        self.cache.insert(*subset, rank);
+        rank
+    }
+
+    fn k(&self) -> usize {
+        self.matroid.k()
+    }
+
+    fn n(&self) -> usize {
+        self.matroid.n()
+    }
+
+    fn to_stored(&self) -> super::StoredMatroidKind {
+        // store the wrapped matroid in its own format
+        self.matroid.to_stored()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::UniformMatroid;
+
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn memoized_is_sync() {
+        assert_sync::<Memoized<UniformMatroid>>();
+    }
+
+    #[test]
+    fn parallel_betti() {
+        let matroid = UniformMatroid::new(3, 6);
+        let memoized = Memoized::new(matroid.clone());
+
+        assert_eq!(
+            memoized.betti().betti_numbers(),
+            matroid.betti().betti_numbers()
+        );
+        assert!(memoized.cache_hits() > 0);
+        assert!(memoized.cache_len() <= 1 << matroid.n());
+    }
+}
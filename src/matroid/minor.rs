@@ -0,0 +1,99 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// The minor M / C \ D of a matroid, where the elements in C are contracted and the elements in
+/// D are deleted.
+///
+/// The remaining elements keep their order, but are re-indexed to 0, 1, ..., n - 1, as in
+/// [`Matroid::minor`]. A set X of them has rank r(X ∪ C) - r(C) in the minor.
+pub struct Minor<'a, M: Matroid> {
+    matroid: &'a M,
+    contract: Set,
+    remaining: Vec<usize>,
+    contract_rank: usize,
+    k: usize,
+}
+
+impl<'a, M: Matroid> Minor<'a, M> {
+    /// create the minor of the matroid where the elements in delete are deleted and the elements
+    /// in contract are contracted. The two sets should be disjoint.
+    pub fn new(matroid: &'a M, delete: &Set, contract: &Set) -> Self {
+        debug_assert!(delete.intersect(contract).is_empty());
+
+        let remaining = Set::of_size(matroid.n()).difference(&delete.union(contract));
+        let contract_rank = matroid.rank(contract);
+        let k = matroid.rank(&remaining.union(contract)) - contract_rank;
+
+        Minor {
+            matroid,
+            contract: *contract,
+            remaining: remaining.bits().collect(),
+            contract_rank,
+            k,
+        }
+    }
+
+    /// the subset of the original matroid that the subset of the minor corresponds to
+    fn original(&self, subset: &Set) -> Set {
+        subset
+            .bits()
+            .map(|e| self.remaining[e])
+            .fold(Set::empty(), |acc, e| acc.add_element(e))
+    }
+}
+
+impl<'a, M: Matroid> Matroid for Minor<'a, M> {
+    fn rank(&self, subset: &Set) -> usize {
+        self.matroid
+            .rank(&self.original(subset).union(&self.contract))
+            - self.contract_rank
+    }
+
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    fn n(&self) -> usize {
+        self.remaining.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::examples::{fano, matroid_1};
+    use crate::matroid::UniformMatroid;
+
+    #[test]
+    fn delete_and_contract() {
+        let u36 = UniformMatroid::new(3, 6);
+        assert!(u36
+            .delete(&0b000110.into())
+            .is_equal(&UniformMatroid::new(3, 4)));
+        assert!(u36
+            .contract(&0b000110.into())
+            .is_equal(&UniformMatroid::new(1, 4)));
+
+        // contracting a point of the Fano plane gives three parallel classes of size two
+        let fano = fano();
+        let contraction = fano.contract(&0b1.into());
+        assert_eq!((contraction.n(), contraction.k()), (6, 2));
+        assert_eq!(contraction.rank(&0b11.into()), 1);
+        assert_eq!(contraction.check_rank_axioms(), Ok(()));
+    }
+
+    #[test]
+    fn same_as_minor() {
+        let m = matroid_1();
+        let (delete, contract) = (Set::from(0b0010_0100), Set::from(0b1000_0001));
+
+        let minor = Minor::new(&m, &delete, &contract);
+        assert!(minor.is_equal(&m.minor(&delete, &contract)));
+
+        // deleting everything leaves the empty matroid
+        let empty = m.delete(&Set::of_size(8));
+        assert_eq!((empty.n(), empty.k()), (0, 0));
+    }
+}
@@ -4,20 +4,68 @@
 mod matroid;
 
 mod bases_matroid;
+mod catalan;
+mod coextension;
 mod combinatorial_derived;
+mod connection;
+mod cyclic_flats;
 mod dual;
 mod elongate;
 pub mod examples;
+mod extension;
+mod flats;
+mod frame;
+mod graphic;
+mod higgs;
+mod laminar;
 mod matrix_matroid;
+mod memoized;
+mod minor;
+mod nested;
+mod oracle;
+mod paving;
+mod positroid;
+pub mod random;
+mod representation;
+mod rigidity;
+mod schubert;
+mod set_ext;
+mod spike;
 mod storage;
+mod transversal;
 mod uniform;
 mod vamos;
+pub mod verify;
 
 pub use bases_matroid::BasesMatroid;
-pub use combinatorial_derived::CombinatorialDerived;
+pub use catalan::CatalanMatroid;
+pub use coextension::FreeCoextension;
+pub use combinatorial_derived::{CombinatorialDerived, CombinatorialDerivedIter};
+pub use connection::{
+    parallel_connection, series_connection, ParallelConnection, SeriesConnection,
+};
+pub use cyclic_flats::{CyclicFlatsLattice, CyclicFlatsMatroid};
 pub use dual::Dual;
 pub use elongate::Elongate;
+pub use extension::PrincipalExtension;
+pub use flats::FlatsMatroid;
+pub use frame::FrameMatroid;
+pub use graphic::GraphicMatroid;
+pub use higgs::{HiggsFamily, HiggsLift};
+pub use laminar::LaminarMatroid;
 pub use matrix_matroid::MatrixMatroid;
-pub use matroid::{load_matroid, Matroid};
+pub use matroid::{load_matroid, load_typed_matroid, read_matroid, Matroid};
+pub use memoized::Memoized;
+pub use minor::Minor;
+pub use nested::NestedMatroid;
+pub use oracle::{IndependenceOracle, Oracle, OracleMatroid, RankOracle};
+pub use paving::{PavingMatroid, SparsePavingMatroid};
+pub use positroid::Positroid;
+pub use rigidity::RigidityMatroid;
+pub use schubert::SchubertMatroid;
+pub use set_ext::SetMatroidExt;
+pub use spike::Spike;
+pub use storage::{LoadedMatroid, StorageError, StorageFormat, StoredMatroidKind};
+pub use transversal::TransversalMatroid;
 pub use uniform::UniformMatroid;
 pub use vamos::Vamos;
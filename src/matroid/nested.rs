@@ -0,0 +1,126 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// A nested matroid, a matroid where the cyclic flats (the flats that are unions of circuits)
+/// form a chain.
+///
+/// The matroid is given by the chain F_1 ⊂ F_2 ⊂ ... ⊂ F_m with the ranks r_1 < r_2 < ... < r_m,
+/// and the rank of a set X is the minimum of |X| and r_i + |X \ F_i| over the chain.
+#[derive(Debug, Clone)]
+pub struct NestedMatroid {
+    n: usize,
+    k: usize,
+    chain: Vec<(Set, usize)>,
+}
+
+impl NestedMatroid {
+    /// Create the nested matroid on n elements where the cyclic flats, other than the empty set,
+    /// are the sets in the chain, with the given ranks. The first set can have rank 0, and is
+    /// then the set of loops.
+    ///
+    /// Is None if the chain is not increasing, or has elements outside the ground set, or if some
+    /// set in the chain would not be a cyclic flat: for two consecutive sets X ⊂ Y in the chain
+    /// (starting with the empty set), the rank has to increase, but by less than |Y \ X|.
+    pub fn new(n: usize, chain: &[(Set, usize)]) -> Option<Self> {
+        let mut previous = (Set::empty(), 0);
+        for (i, &(set, rank)) in chain.iter().enumerate() {
+            let added = set.difference(&previous.0);
+            let loops = i == 0 && rank == 0;
+            if !previous.0.difference(&set).is_empty()
+                || !set.difference(&Set::of_size(n)).is_empty()
+                || !(loops || (rank > previous.1 && rank - previous.1 < added.size()))
+            {
+                return None;
+            }
+            previous = (set, rank);
+        }
+
+        let mut matroid = NestedMatroid {
+            n,
+            k: 0,
+            chain: chain.to_vec(),
+        };
+        matroid.k = matroid.rank(&Set::of_size(n));
+
+        Some(matroid)
+    }
+
+    /// the chain of cyclic flats with their ranks
+    pub fn chain(&self) -> &[(Set, usize)] {
+        &self.chain
+    }
+}
+
+impl Matroid for NestedMatroid {
+    fn rank(&self, subset: &Set) -> usize {
+        self.chain
+            .iter()
+            .map(|(flat, rank)| rank + subset.difference(flat).size())
+            .fold(subset.size(), usize::min)
+    }
+
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    fn n(&self) -> usize {
+        self.n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::{LaminarMatroid, UniformMatroid};
+
+    #[test]
+    fn uniform() {
+        let nested = NestedMatroid::new(6, &[(Set::of_size(6), 3)]).unwrap();
+        assert!(nested.is_equal(&UniformMatroid::new(3, 6)));
+
+        let free = NestedMatroid::new(4, &[]).unwrap();
+        assert!(free.is_equal(&UniformMatroid::new(4, 4)));
+    }
+
+    #[test]
+    fn chain() {
+        let chain = [
+            (Set::from(0b00000001), 0),
+            (Set::from(0b00001111), 2),
+            (Set::from(0b01111111), 4),
+        ];
+        let nested = NestedMatroid::new(8, &chain).unwrap();
+
+        assert_eq!(nested.k(), 5);
+        assert_eq!(nested.check_rank_axioms(), Ok(()));
+
+        // the members of the chain are cyclic flats
+        for (flat, rank) in nested.chain() {
+            assert!(nested.is_flat(flat));
+            assert_eq!(nested.rank(flat), *rank);
+            assert!(flat
+                .bits()
+                .all(|e| nested.rank(&flat.remove_element(e)) == *rank));
+        }
+
+        // a chain is a laminar family, and the capacities are the ranks
+        let laminar = LaminarMatroid::new(8, &chain).unwrap();
+        assert!(nested.is_equal(&laminar));
+    }
+
+    #[test]
+    fn invalid() {
+        // not a chain
+        let chain = [(Set::from(0b0111), 1), (Set::from(0b1110), 2)];
+        assert!(NestedMatroid::new(4, &chain).is_none());
+
+        // the set {0, 1} would be independent, and not a cyclic flat
+        assert!(NestedMatroid::new(4, &[(Set::from(0b0011), 2)]).is_none());
+
+        // the rank does not increase
+        let chain = [(Set::from(0b0111), 1), (Set::from(0b1111), 1)];
+        assert!(NestedMatroid::new(4, &chain).is_none());
+    }
+}
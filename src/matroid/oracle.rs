@@ -0,0 +1,150 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// A matroid given by an oracle, either for the independent sets or for the rank function.
+///
+/// Nothing is precomputed, every query is answered by asking the oracle. With an independence
+/// oracle the rank of a subset is found greedily by adding the elements of the subset one at a
+/// time while the set stays independent, so every rank query makes up to |X| calls to the
+/// oracle. This is useful when the oracle is expensive, and only a few ranks are needed.
+pub struct OracleMatroid<O> {
+    n: usize,
+    k: usize,
+    oracle: O,
+}
+
+/// The oracles an [`OracleMatroid`] can be built from.
+pub trait Oracle {
+    /// the rank of the subset
+    fn rank(&self, subset: &Set) -> usize;
+
+    /// checks if the subset is independent
+    fn is_independent(&self, subset: &Set) -> bool {
+        self.rank(subset) == subset.size()
+    }
+}
+
+/// An oracle telling whether a set is independent
+pub struct IndependenceOracle<F>(F);
+
+/// An oracle giving the rank of a set
+pub struct RankOracle<F>(F);
+
+impl<F: Fn(&Set) -> bool> Oracle for IndependenceOracle<F> {
+    fn rank(&self, subset: &Set) -> usize {
+        // all maximal independent subsets have the same size, so the greedy choice finds a basis
+        subset
+            .bits()
+            .fold(Set::empty(), |independent, e| {
+                let candidate = independent.add_element(e);
+                if (self.0)(&candidate) {
+                    candidate
+                } else {
+                    independent
+                }
+            })
+            .size()
+    }
+
+    fn is_independent(&self, subset: &Set) -> bool {
+        (self.0)(subset)
+    }
+}
+
+impl<F: Fn(&Set) -> usize> Oracle for RankOracle<F> {
+    fn rank(&self, subset: &Set) -> usize {
+        (self.0)(subset)
+    }
+}
+
+impl<F: Fn(&Set) -> usize> OracleMatroid<RankOracle<F>> {
+    /// Create a matroid of rank k on n elements from the rank function, e.g. from an external
+    /// solver. The rank function is trusted to satisfy the rank axioms, see
+    /// [`Matroid::check_rank_axioms`] for checking this on small ground sets.
+    pub fn new(n: usize, k: usize, rank_fn: F) -> Self {
+        Self {
+            n,
+            k,
+            oracle: RankOracle(rank_fn),
+        }
+    }
+}
+
+impl<F: Fn(&Set) -> bool> OracleMatroid<IndependenceOracle<F>> {
+    /// Create a matroid of rank k on n elements, where the independent sets are the sets
+    /// accepted by the oracle.
+    pub fn from_independence_oracle(n: usize, k: usize, oracle: F) -> Self {
+        Self {
+            n,
+            k,
+            oracle: IndependenceOracle(oracle),
+        }
+    }
+}
+
+impl<O: Oracle> Matroid for OracleMatroid<O> {
+    fn rank(&self, subset: &Set) -> usize {
+        self.oracle.rank(subset)
+    }
+
+    fn is_independent(&self, subset: &Set) -> bool {
+        self.oracle.is_independent(subset)
+    }
+
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    fn n(&self) -> usize {
+        self.n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::UniformMatroid;
+    use crate::set::SetIterator;
+
+    use std::cell::Cell;
+
+    #[test]
+    fn uniform_oracle() {
+        let u36 = UniformMatroid::new(3, 6);
+        let oracle = OracleMatroid::from_independence_oracle(6, 3, |s: &Set| u36.is_independent(s));
+
+        assert!(oracle.is_equal(&u36));
+        assert!(SetIterator::new(6).all(|s| oracle.rank(&s) == u36.rank(&s)));
+        assert_eq!(oracle.circuits(), u36.circuits());
+    }
+
+    #[test]
+    fn rank_oracle() {
+        let u36 = UniformMatroid::new(3, 6);
+        let oracle = OracleMatroid::new(6, 3, |s: &Set| s.size().min(3));
+
+        assert!(oracle.is_equal(&u36));
+        assert_eq!(oracle.circuits(), u36.circuits());
+        assert!(oracle.dual().is_equal(&u36.dual()));
+        assert!(oracle.elongate(1).is_equal(&u36.elongate(1)));
+        assert_eq!(oracle.betti().betti_numbers(), u36.betti().betti_numbers());
+        assert!(oracle
+            .combinatorial_derived()
+            .is_equal(&u36.combinatorial_derived()));
+    }
+
+    #[test]
+    fn lazy() {
+        let calls = Cell::new(0);
+        let oracle = OracleMatroid::from_independence_oracle(10, 4, |s: &Set| {
+            calls.set(calls.get() + 1);
+            s.size() <= 4
+        });
+
+        assert_eq!(calls.get(), 0);
+        assert_eq!(oracle.rank(&0b111111.into()), 4);
+        assert_eq!(calls.get(), 6);
+    }
+}
@@ -0,0 +1,285 @@
+use crate::set::{Set, SetIterator};
+
+use super::Matroid;
+
+use num_integer::binomial;
+
+/// A paving matroid, a matroid where every circuit has at least rank many elements.
+///
+/// The matroid is stored by its hyperplanes, which for a paving matroid of rank r is a family of
+/// sets with at least r - 1 elements, such that every set of r - 1 elements is contained in
+/// exactly one of them. A set of at least r elements is dependent exactly when it is contained
+/// in a hyperplane, so the rank oracle only has to look through the hyperplanes.
+#[derive(Debug, Clone)]
+pub struct PavingMatroid {
+    n: usize,
+    k: usize,
+    hyperplanes: Vec<Set>,
+}
+
+impl PavingMatroid {
+    /// Create the paving matroid of the given rank on n elements from its hyperplanes.
+    /// Is None if the hyperplanes do not give a paving matroid, i.e. if a hyperplane has fewer
+    /// than rank - 1 elements, is the whole ground set, or if some set of rank - 1 elements is not
+    /// contained in exactly one hyperplane.
+    pub fn from_hyperplanes(rank: usize, n: usize, hyperplanes: Vec<Set>) -> Option<Self> {
+        let ground_set = Set::of_size(n);
+        if rank == 0
+            || rank > n
+            || hyperplanes.iter().any(|h| {
+                h.size() + 1 < rank || *h == ground_set || !h.difference(&ground_set).is_empty()
+            })
+        {
+            return None;
+        }
+
+        let is_partition = SetIterator::new(n)
+            .size_limit(rank - 1)
+            .equal()
+            .all(|s| hyperplanes.iter().filter(|h| s <= **h).count() == 1);
+        if !is_partition {
+            return None;
+        }
+
+        Some(PavingMatroid {
+            n,
+            k: rank,
+            hyperplanes,
+        })
+    }
+
+    /// the hyperplanes of the matroid
+    pub fn hyperplanes(&self) -> &[Set] {
+        &self.hyperplanes
+    }
+}
+
+impl Matroid for PavingMatroid {
+    fn rank(&self, subset: &Set) -> usize {
+        // every set of fewer than k elements is independent
+        if subset.size() < self.k {
+            return subset.size();
+        }
+
+        if self.hyperplanes.iter().any(|h| subset <= h) {
+            self.k - 1
+        } else {
+            self.k
+        }
+    }
+
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    fn n(&self) -> usize {
+        self.n
+    }
+}
+
+/// A sparse paving matroid, a paving matroid whose dual is also paving.
+///
+/// The matroid is stored by its circuit-hyperplanes, the sets of r elements that are not bases,
+/// and any two of them share at most r - 2 elements. Every other set of r elements is a basis,
+/// so the rank oracle only has to look for the set among the circuit-hyperplanes.
+#[derive(Debug, Clone)]
+pub struct SparsePavingMatroid {
+    n: usize,
+    k: usize,
+    circuit_hyperplanes: Vec<Set>,
+}
+
+impl SparsePavingMatroid {
+    /// Create the sparse paving matroid of the given rank on n elements with the given
+    /// circuit-hyperplanes. Is None if a set does not have rank elements, or if two of them share
+    /// more than rank - 2 elements. If the rank is 0 or n, there is only one set of rank elements,
+    /// and it has to be a basis, so then the list has to be empty.
+    pub fn new(rank: usize, n: usize, circuit_hyperplanes: Vec<Set>) -> Option<Self> {
+        let ground_set = Set::of_size(n);
+        if rank > n
+            || ((rank == 0 || rank == n) && !circuit_hyperplanes.is_empty())
+            || circuit_hyperplanes
+                .iter()
+                .any(|c| c.size() != rank || !c.difference(&ground_set).is_empty())
+        {
+            return None;
+        }
+
+        let sparse = circuit_hyperplanes.iter().enumerate().all(|(i, a)| {
+            circuit_hyperplanes[i + 1..]
+                .iter()
+                .all(|b| a.intersect(b).size() + 2 <= rank)
+        });
+        if !sparse {
+            return None;
+        }
+
+        Some(SparsePavingMatroid {
+            n,
+            k: rank,
+            circuit_hyperplanes,
+        })
+    }
+
+    /// Create the sparse paving matroid of a Steiner system S(t, k, n), where the blocks of k
+    /// elements are the circuit-hyperplanes of a matroid of rank k.
+    ///
+    /// Is None if the blocks do not form a Steiner system with 0 < t < k, i.e. if the blocks do
+    /// not all have the same size k, or if some set of t elements is not contained in exactly one
+    /// block. Two blocks share at most t - 1 <= k - 2 elements, so the matroid is sparse paving.
+    pub fn from_steiner_system(t: usize, n: usize, blocks: Vec<Set>) -> Option<Self> {
+        let k = blocks.first()?.size();
+        if t == 0 || t >= k {
+            return None;
+        }
+
+        // no set of t elements is in two blocks, so the blocks cover exactly
+        // |blocks| * binomial(k, t) of them, which has to be all of them
+        let disjoint = blocks
+            .iter()
+            .enumerate()
+            .all(|(i, a)| blocks[i + 1..].iter().all(|b| a.intersect(b).size() < t));
+        if !disjoint || blocks.len() * binomial(k, t) != binomial(n, t) {
+            return None;
+        }
+
+        Self::new(k, n, blocks)
+    }
+
+    /// the circuit-hyperplanes of the matroid
+    pub fn circuit_hyperplanes(&self) -> &[Set] {
+        &self.circuit_hyperplanes
+    }
+}
+
+impl Matroid for SparsePavingMatroid {
+    fn rank(&self, subset: &Set) -> usize {
+        if subset.size() == self.k && self.circuit_hyperplanes.contains(subset) {
+            self.k - 1
+        } else {
+            subset.size().min(self.k)
+        }
+    }
+
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    fn n(&self) -> usize {
+        self.n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::examples::{ag32, fano};
+    use crate::matroid::{UniformMatroid, Vamos};
+
+    #[test]
+    fn fano_plane() {
+        let lines = [
+            0b0000111, 0b0011001, 0b1100001, 0b0101010, 0b1010010, 0b1001100, 0b0110100,
+        ]
+        .map(Set::from)
+        .to_vec();
+
+        let paving = PavingMatroid::from_hyperplanes(3, 7, lines).unwrap();
+        assert!(paving.is_equal(&fano()));
+        assert_eq!(paving.hyperplanes().len(), 7);
+    }
+
+    #[test]
+    fn uniform() {
+        // every pair is a hyperplane of U(3, 6)
+        let pairs = SetIterator::new(6).size_limit(2).equal().collect();
+        let paving = PavingMatroid::from_hyperplanes(3, 6, pairs).unwrap();
+
+        assert!(paving.is_equal(&UniformMatroid::new(3, 6)));
+    }
+
+    #[test]
+    fn vamos() {
+        // the five circuits of size four, and every triple not contained in one of them
+        let vamos = Vamos::new();
+        let planes: Vec<Set> = vamos
+            .circuits()
+            .into_iter()
+            .filter(|c| c.size() == 4)
+            .collect();
+        let hyperplanes = SetIterator::new(8)
+            .size_limit(3)
+            .equal()
+            .filter(|s| !planes.iter().any(|p| s <= p))
+            .chain(planes.iter().copied())
+            .collect();
+
+        let paving = PavingMatroid::from_hyperplanes(4, 8, hyperplanes).unwrap();
+        assert!(paving.is_equal(&vamos));
+    }
+
+    #[test]
+    fn invalid() {
+        // the pair {0, 1} is in two hyperplanes
+        let hyperplanes = vec![0b0111.into(), 0b1011.into(), 0b1100.into()];
+        assert!(PavingMatroid::from_hyperplanes(3, 4, hyperplanes).is_none());
+
+        // the pair {2, 3} is not in any hyperplane
+        let hyperplanes = vec![0b0111.into(), 0b1001.into()];
+        assert!(PavingMatroid::from_hyperplanes(3, 4, hyperplanes).is_none());
+
+        assert!(PavingMatroid::from_hyperplanes(2, 2, vec![0b11.into()]).is_none());
+    }
+
+    #[test]
+    fn steiner_systems() {
+        // the lines of the Fano plane form S(2, 3, 7)
+        let lines = [
+            0b0000111, 0b0011001, 0b1100001, 0b0101010, 0b1010010, 0b1001100, 0b0110100,
+        ]
+        .map(Set::from)
+        .to_vec();
+        let matroid = SparsePavingMatroid::from_steiner_system(2, 7, lines.clone()).unwrap();
+        assert!(matroid.is_equal(&fano()));
+
+        // the planes of AG(3, 2) form S(3, 4, 8)
+        let ag32 = ag32();
+        let planes: Vec<Set> = ag32
+            .circuits()
+            .into_iter()
+            .filter(|c| c.size() == 4)
+            .collect();
+        assert_eq!(planes.len(), 14);
+        let matroid = SparsePavingMatroid::from_steiner_system(3, 8, planes).unwrap();
+        assert!(matroid.is_equal(&ag32));
+        assert_eq!(matroid.check_rank_axioms(), Ok(()));
+
+        // without one line, some pairs are not in any block
+        assert!(SparsePavingMatroid::from_steiner_system(2, 7, lines[1..].to_vec()).is_none());
+        // every pair is in a line, but then every triple has to be in a block
+        assert!(SparsePavingMatroid::from_steiner_system(3, 7, lines).is_none());
+    }
+
+    #[test]
+    fn sparse_paving() {
+        // two circuit-hyperplanes of U(3, 6) sharing two elements is not sparse paving
+        let sets = vec![0b000111.into(), 0b001011.into()];
+        assert!(SparsePavingMatroid::new(3, 6, sets).is_none());
+
+        let sets = vec![0b000111.into(), 0b111000.into()];
+        let matroid = SparsePavingMatroid::new(3, 6, sets).unwrap();
+        assert_eq!(matroid.bases().len(), 18);
+        assert_eq!(matroid.check_rank_axioms(), Ok(()));
+
+        // the empty set can not be a circuit-hyperplane of a matroid of rank 0
+        assert!(SparsePavingMatroid::new(0, 3, vec![Set::empty()]).is_none());
+        let matroid = SparsePavingMatroid::new(0, 3, Vec::new()).unwrap();
+        assert!(matroid.is_equal(&UniformMatroid::new(0, 3)));
+
+        // and the ground set can not be a circuit-hyperplane of a matroid of rank n
+        assert!(SparsePavingMatroid::new(3, 3, vec![Set::of_size(3)]).is_none());
+        let matroid = SparsePavingMatroid::new(3, 3, Vec::new()).unwrap();
+        assert!(matroid.is_equal(&UniformMatroid::new(3, 3)));
+    }
+}
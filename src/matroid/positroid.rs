@@ -0,0 +1,177 @@
+use crate::set::{Set, SetIterator};
+
+use super::{BasesMatroid, Matroid};
+
+/// A positroid, the matroid of a real matrix with all maximal minors non-negative.
+///
+/// The positroid is given by a decorated permutation, from which the Grassmann necklace
+/// (I_0, ..., I_{n-1}) is found, where I_i is the lexicographically smallest basis in the cyclic
+/// order starting at i. The rank of the cyclic interval [i, j] is then |I_i ∩ [i, j]|, and by the
+/// theorem of Oh the bases are the sets that are at least I_i in the Gale order for the cyclic
+/// order starting at i, for every i.
+#[derive(Debug, Clone)]
+pub struct Positroid {
+    n: usize,
+    k: usize,
+    necklace: Vec<Set>,
+    bases: Vec<Set>,
+}
+
+impl Positroid {
+    /// Create the positroid of the decorated permutation, where the element i is mapped to
+    /// permutation[i]. The fixed points in coloops are coloops, the other fixed points are loops.
+    /// Is None if permutation is not a permutation of 0, ..., n - 1, or if coloops has an element
+    /// that is not a fixed point.
+    pub fn from_decorated_permutation(permutation: &[usize], coloops: &Set) -> Option<Self> {
+        let n = permutation.len();
+        let mut inverse = vec![n; n];
+        for (i, &j) in permutation.iter().enumerate() {
+            if j >= n || inverse[j] != n {
+                return None;
+            }
+            inverse[j] = i;
+        }
+        if coloops.bits().any(|e| e >= n || permutation[e] != e) {
+            return None;
+        }
+
+        // the element j enters the necklace after inverse[j], and leaves it after j, so it is in
+        // I_i for the i in the cyclic interval [inverse[j] + 1, j]
+        let necklace: Vec<Set> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .filter(|&j| match inverse[j] {
+                        a if a == j => coloops.contains_element(j),
+                        a => (i + n - a - 1) % n <= (j + n - a - 1) % n,
+                    })
+                    .collect::<Vec<usize>>()
+                    .into()
+            })
+            .collect();
+
+        let k = necklace.first().map_or(0, |s| s.size());
+        let bases = SetIterator::new(n)
+            .size_limit(k)
+            .equal()
+            .filter(|b| {
+                necklace
+                    .iter()
+                    .enumerate()
+                    .all(|(i, necklace_set)| gale_at_least(b, necklace_set, i, n))
+            })
+            .collect();
+
+        Some(Positroid {
+            n,
+            k,
+            necklace,
+            bases,
+        })
+    }
+
+    /// The Grassmann necklace (I_0, ..., I_{n-1}) of the positroid
+    pub fn grassmann_necklace(&self) -> &[Set] {
+        &self.necklace
+    }
+
+    /// The rank of the cyclic interval of length len starting at start, i.e. the set
+    /// {start, start + 1, ..., start + len - 1} with the elements taken modulo n.
+    pub fn cyclic_interval_rank(&self, start: usize, len: usize) -> usize {
+        (0..len.min(self.n))
+            .filter(|i| self.necklace[start].contains_element((start + i) % self.n))
+            .count()
+    }
+}
+
+/// checks if a is at least b in the Gale order for the cyclic order starting at start, i.e. if
+/// the i-th smallest element of a is at least the i-th smallest element of b in that order
+fn gale_at_least(a: &Set, b: &Set, start: usize, n: usize) -> bool {
+    let shifted = |s: &Set| {
+        let mut elements: Vec<usize> = s.bits().map(|e| (e + n - start) % n).collect();
+        elements.sort();
+        elements
+    };
+
+    shifted(a).into_iter().zip(shifted(b)).all(|(x, y)| x >= y)
+}
+
+impl Matroid for Positroid {
+    fn rank(&self, subset: &Set) -> usize {
+        BasesMatroid::rank_of_subset_given_bases(subset, &self.bases)
+    }
+
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    fn n(&self) -> usize {
+        self.n
+    }
+
+    fn bases(&self) -> Vec<Set> {
+        self.bases.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::UniformMatroid;
+
+    #[test]
+    fn uniform() {
+        // the permutation i -> i + k gives the uniform matroid U(k, n)
+        for (k, n) in [(2, 4), (3, 6), (1, 5)] {
+            let permutation: Vec<usize> = (0..n).map(|i| (i + k) % n).collect();
+            let positroid = Positroid::from_decorated_permutation(&permutation, &Set::empty());
+
+            assert!(positroid.unwrap().is_equal(&UniformMatroid::new(k, n)));
+        }
+    }
+
+    #[test]
+    fn fixed_points() {
+        let identity = [0, 1, 2, 3];
+        let free = Positroid::from_decorated_permutation(&identity, &Set::of_size(4)).unwrap();
+        assert!(free.is_equal(&UniformMatroid::new(4, 4)));
+
+        let empty = Positroid::from_decorated_permutation(&identity, &Set::empty()).unwrap();
+        assert!(empty.is_equal(&UniformMatroid::new(0, 4)));
+
+        // the element 1 is a coloop, and 3 is a loop
+        let positroid =
+            Positroid::from_decorated_permutation(&[2, 1, 0, 3], &Set::from(0b0010)).unwrap();
+        assert_eq!(positroid.k(), 2);
+        assert!(positroid.bases().iter().all(|b| b.contains_element(1)));
+        assert!(positroid.bases().iter().all(|b| !b.contains_element(3)));
+    }
+
+    #[test]
+    fn necklace() {
+        let positroid =
+            Positroid::from_decorated_permutation(&[2, 4, 0, 5, 1, 3], &Set::empty()).unwrap();
+        assert_eq!(positroid.check_rank_axioms(), Ok(()));
+
+        let n = positroid.n();
+        for (i, necklace_set) in positroid.grassmann_necklace().iter().enumerate() {
+            assert_eq!(necklace_set.size(), positroid.k());
+            // every set in the necklace is a basis
+            assert!(positroid.is_independent(necklace_set));
+
+            for len in 0..=n {
+                let interval: Set = (0..len).map(|j| (i + j) % n).collect::<Vec<_>>().into();
+                assert_eq!(
+                    positroid.cyclic_interval_rank(i, len),
+                    positroid.rank(&interval)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Positroid::from_decorated_permutation(&[0, 0, 1], &Set::empty()).is_none());
+        assert!(Positroid::from_decorated_permutation(&[1, 0], &Set::from(0b01)).is_none());
+    }
+}
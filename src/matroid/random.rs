@@ -0,0 +1,96 @@
+//! Random matroids, for testing the calculations on many inputs.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::set::{Set, SetIterator};
+
+use super::BasesMatroid;
+
+/// A random sparse paving matroid of rank k on n elements.
+///
+/// The sets of size k are visited in a random order, and each is made a circuit-hyperplane with
+/// probability 1/2 if it shares at most k - 2 elements with every circuit-hyperplane chosen so
+/// far. Any such family gives a matroid, where the bases are the other sets of size k.
+/// Conjecturally almost all matroids are sparse paving, so this is a good source of generic
+/// matroids. If k is 0 or n, the only matroid is the uniform matroid U(k, n).
+///
+/// Panics if k is larger than n, as there is no matroid of rank k on n elements.
+pub fn random_bases_matroid<R: Rng + ?Sized>(n: usize, k: usize, rng: &mut R) -> BasesMatroid {
+    assert!(k <= n, "there is no matroid of rank {} on {} elements", k, n);
+    let mut sets: Vec<Set> = SetIterator::new(n).size_limit(k).equal().collect();
+
+    if k == 0 || k == n {
+        return BasesMatroid::new(sets, n, k);
+    }
+
+    sets.shuffle(rng);
+
+    let mut circuit_hyperplanes: Vec<Set> = Vec::new();
+    let mut bases = Vec::new();
+    for set in sets {
+        let compatible = circuit_hyperplanes
+            .iter()
+            .all(|c| c.intersect(&set).size() + 2 <= k);
+
+        if compatible && rng.gen_bool(0.5) {
+            circuit_hyperplanes.push(set);
+        } else {
+            bases.push(set);
+        }
+    }
+
+    BasesMatroid::new(bases, n, k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::betti_nums::BettiNumbers;
+    use crate::matroid::Matroid;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn valid_matroids() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for (n, k) in [(6, 3), (7, 2), (7, 4), (5, 1)] {
+            let matroid = random_bases_matroid(n, k, &mut rng);
+
+            assert_eq!((matroid.n(), matroid.k()), (n, k));
+            assert_eq!(matroid.check_rank_axioms(), Ok(()));
+            // the matroid is paving, so every circuit has at least k elements
+            assert!(matroid.girth().unwrap_or(k) >= k);
+        }
+
+        let free = random_bases_matroid(4, 4, &mut rng);
+        assert_eq!(free.bases(), vec![Set::of_size(4)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "no matroid of rank 5 on 4 elements")]
+    fn rank_larger_than_n() {
+        random_bases_matroid(4, 5, &mut StdRng::seed_from_u64(7));
+    }
+
+    #[test]
+    fn seeded() {
+        let a = random_bases_matroid(7, 3, &mut StdRng::seed_from_u64(3));
+        let b = random_bases_matroid(7, 3, &mut StdRng::seed_from_u64(3));
+
+        assert!(a.is_equal(&b));
+    }
+
+    #[test]
+    fn fuzz_betti() {
+        let mut rng = StdRng::seed_from_u64(11);
+
+        for _ in 0..5 {
+            let matroid = random_bases_matroid(6, 3, &mut rng);
+            assert!(BettiNumbers::new(&matroid) == BettiNumbers::new_direct(&matroid));
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::field::FiniteField;
+use crate::matrix::{DynMatrix, Matrix};
+use crate::set::{Set, SetIterator};
+
+use tinyfield::prime_field::PrimeFieldElt;
+use tinyfield::GF2;
+
+use super::{GraphicMatroid, MatrixMatroid, Matroid};
+
+/// Search for a matrix over the field F whose column matroid is the matroid.
+///
+/// The matrix is searched for in standard form [I | A] with respect to a basis B. The support of
+/// every column of A is then given by the fundamental circuit of the element with respect to B,
+/// and the first non-zero entry of every column may be scaled to one. The remaining entries are
+/// tried with every non-zero value of the field, so this takes (q - 1)^m steps, where m is the
+/// number of free entries. It is only feasible for small matroids over small fields.
+pub(crate) fn find_representation<F: FiniteField, M: Matroid>(matroid: &M) -> Option<DynMatrix<F>> {
+    let n = matroid.n();
+    let k = matroid.k();
+
+    let basis = SetIterator::new(n)
+        .size_limit(k)
+        .equal()
+        .find(|s| matroid.is_independent(s))?;
+    let basis_elements: Vec<usize> = basis.into();
+
+    let zero = F::from(0);
+    let one = F::from(1);
+    let non_zero: Vec<F> = F::elements().into_iter().filter(|e| *e != zero).collect();
+
+    let mut matrix = DynMatrix::new(k, n);
+    // the entries that are not fixed by the normalization
+    let mut free = Vec::new();
+
+    for (row, &b) in basis_elements.iter().enumerate() {
+        matrix[(row, b)] = one;
+    }
+
+    for e in (0..n).filter(|e| !basis.contains_element(*e)) {
+        // the rows of the basis elements in the fundamental circuit of e
+        let support: Vec<usize> = basis_elements
+            .iter()
+            .enumerate()
+            .filter(|(_, &b)| matroid.is_independent(&basis.remove_element(b).add_element(e)))
+            .map(|(row, _)| row)
+            .collect();
+
+        if let Some((&first, rest)) = support.split_first() {
+            matrix[(first, e)] = one;
+            free.extend(rest.iter().map(|&row| (row, e)));
+        }
+    }
+
+    // the index into non_zero of the value of every free entry
+    let mut values = vec![0; free.len()];
+    loop {
+        for (&(row, col), &value) in free.iter().zip(values.iter()) {
+            matrix[(row, col)] = non_zero[value];
+        }
+
+        let candidate = MatrixMatroid::from(Matrix::clone(&matrix));
+        if candidate.k() == k
+            && SetIterator::new(n)
+                .size_limit(k)
+                .equal()
+                .all(|s| candidate.is_independent(&s) == matroid.is_independent(&s))
+        {
+            return Some(matrix);
+        }
+
+        // go to the next assignment of values
+        let mut i = 0;
+        while i < values.len() && values[i] + 1 == non_zero.len() {
+            values[i] = 0;
+            i += 1;
+        }
+        if i == values.len() {
+            return None;
+        }
+        values[i] += 1;
+    }
+}
+
+/// Search for a graph whose cycle matroid is the matroid.
+/// Returns the number of vertices and the edges, where edge e is the element e of the matroid.
+///
+/// A graphic matroid is binary, and the row space of a binary representation is the cocycle space
+/// of the matroid. The vertex stars of a connected graph on k + 1 vertices are cocycles, and any k
+/// of them form a basis of the cocycle space where every element is in at most two of the basis
+/// vectors. So this searches for such a basis, and reads the graph off the rows, with vertex k as
+/// the vertex whose star is left out.
+pub(crate) fn find_graph<M: Matroid>(matroid: &M) -> Option<(usize, Vec<(usize, usize)>)> {
+    let n = matroid.n();
+    let k = matroid.k();
+    let matrix = find_representation::<PrimeFieldElt<GF2>, _>(matroid)?;
+
+    let zero = PrimeFieldElt::<GF2>::from(0);
+    let rows: Vec<Set> = (0..k)
+        .map(|i| {
+            (0..n)
+                .filter(|&j| matrix[(i, j)] != zero)
+                .fold(Set::empty(), |acc, j| acc.add_element(j))
+        })
+        .collect();
+
+    // every non-zero vector in the cocycle space
+    let cocycles: Vec<Set> = SetIterator::new(k)
+        .filter(|combination| !combination.is_empty())
+        .map(|combination| {
+            combination
+                .bits()
+                .fold(Set::empty(), |acc, i| acc.symmetric_difference(&rows[i]))
+        })
+        .collect();
+
+    let mut stars = Vec::with_capacity(k);
+    let mut degrees = vec![0; n];
+    find_stars(matroid, &cocycles, 0, &mut stars, &mut degrees).map(|edges| (k + 1, edges))
+}
+
+/// Extend the chosen stars with stars from cocycles[start..], such that no element is in more than
+/// two stars, until the graph they describe has the matroid as cycle matroid.
+fn find_stars<M: Matroid>(
+    matroid: &M,
+    cocycles: &[Set],
+    start: usize,
+    stars: &mut Vec<Set>,
+    degrees: &mut Vec<u8>,
+) -> Option<Vec<(usize, usize)>> {
+    let k = matroid.k();
+    if stars.len() == k {
+        let edges = edges_of_stars(matroid.n(), stars);
+        return if GraphicMatroid::new(k + 1, &edges)?.is_equal(matroid) {
+            Some(edges)
+        } else {
+            None
+        };
+    }
+
+    for (i, cocycle) in cocycles.iter().enumerate().skip(start) {
+        if cocycle.bits().any(|e| degrees[e] == 2) {
+            continue;
+        }
+
+        stars.push(*cocycle);
+        cocycle.bits().for_each(|e| degrees[e] += 1);
+
+        if let Some(edges) = find_stars(matroid, cocycles, i + 1, stars, degrees) {
+            return Some(edges);
+        }
+
+        stars.pop();
+        cocycle.bits().for_each(|e| degrees[e] -= 1);
+    }
+
+    None
+}
+
+/// The edges described by the stars of the vertices 0..k, where vertex k is the missing star.
+/// An element that is in no star is a loop.
+fn edges_of_stars(n: usize, stars: &[Set]) -> Vec<(usize, usize)> {
+    let root = stars.len();
+    (0..n)
+        .map(|e| {
+            let mut ends = (0..root).filter(|&v| stars[v].contains_element(e));
+            match (ends.next(), ends.next()) {
+                (Some(u), Some(v)) => (u, v),
+                (Some(u), None) => (u, root),
+                _ => (0, 0),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::field::{GF3, GF4};
+    use crate::matroid::UniformMatroid;
+
+    #[test]
+    fn representation_of_uniform() {
+        let u24 = UniformMatroid::new(2, 4);
+
+        assert!(find_representation::<PrimeFieldElt<GF2>, _>(&u24).is_none());
+
+        let matrix = find_representation::<GF3, _>(&u24).unwrap();
+        assert!(MatrixMatroid::from(matrix).is_equal(&u24));
+
+        let u25 = UniformMatroid::new(2, 5);
+        assert!(find_representation::<GF3, _>(&u25).is_none());
+        assert!(find_representation::<GF4, _>(&u25).is_some());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// The generic rigidity matroid of a graph in the plane.
+///
+/// A set of edges is independent if it is (2, 3)-sparse, i.e. if every non-empty subset of it
+/// spans at least (|F| + 3) / 2 vertices, so |F| <= 2|V(F)| - 3 (Laman's theorem). The rank is
+/// found with the (2, 3) pebble game of Lee and Streinu, which adds the edges one at a time and
+/// keeps the independent ones.
+#[derive(Debug, Clone)]
+pub struct RigidityMatroid {
+    vertices: usize,
+    edges: Vec<(usize, usize)>,
+    k: usize,
+}
+
+impl RigidityMatroid {
+    /// Create the rigidity matroid of the graph with the given number of vertices, where element
+    /// i is the edge edges[i]. Is None if an edge has an endpoint out of range.
+    pub fn new(vertices: usize, edges: &[(usize, usize)]) -> Option<Self> {
+        if edges.iter().any(|&(u, v)| u >= vertices || v >= vertices) {
+            return None;
+        }
+
+        let mut matroid = RigidityMatroid {
+            vertices,
+            edges: edges.to_vec(),
+            k: 0,
+        };
+        matroid.k = matroid.rank(&Set::of_size(edges.len()));
+
+        Some(matroid)
+    }
+
+    /// checks if the graph is rigid, i.e. if the edges have rank 2|V| - 3 (or the graph has at
+    /// most one vertex)
+    pub fn is_rigid(&self) -> bool {
+        self.vertices < 2 || self.k == 2 * self.vertices - 3
+    }
+}
+
+/// The state of the (2, 3) pebble game, where every vertex starts with two pebbles, and every
+/// accepted edge is covered by a pebble from one of its endpoints, which is the tail of the edge.
+struct PebbleGame {
+    pebbles: Vec<usize>,
+    out_edges: Vec<Vec<usize>>,
+}
+
+impl PebbleGame {
+    fn new(vertices: usize) -> Self {
+        PebbleGame {
+            pebbles: vec![2; vertices],
+            out_edges: vec![Vec::new(); vertices],
+        }
+    }
+
+    /// try to move a free pebble to u, without taking one from v, by reversing a directed path
+    /// from u to a vertex with a free pebble
+    fn gather(&mut self, u: usize, v: usize) -> bool {
+        let mut parent: Vec<Option<usize>> = vec![None; self.pebbles.len()];
+        let mut visited = vec![false; self.pebbles.len()];
+        visited[u] = true;
+        visited[v] = true;
+
+        let mut stack = vec![u];
+        while let Some(x) = stack.pop() {
+            for &y in &self.out_edges[x] {
+                if visited[y] {
+                    continue;
+                }
+                visited[y] = true;
+                parent[y] = Some(x);
+
+                if self.pebbles[y] > 0 {
+                    // reverse the path from u to y
+                    self.pebbles[y] -= 1;
+                    self.pebbles[u] += 1;
+                    let mut current = y;
+                    while let Some(previous) = parent[current] {
+                        let position = self.out_edges[previous]
+                            .iter()
+                            .position(|&z| z == current)
+                            .unwrap();
+                        self.out_edges[previous].swap_remove(position);
+                        self.out_edges[current].push(previous);
+                        current = previous;
+                    }
+                    return true;
+                }
+                stack.push(y);
+            }
+        }
+
+        false
+    }
+
+    /// add the edge if it is independent of the edges added so far, i.e. if four pebbles can be
+    /// gathered on its endpoints
+    fn try_add(&mut self, u: usize, v: usize) -> bool {
+        if u == v {
+            return false;
+        }
+
+        while self.pebbles[u] + self.pebbles[v] < 4 {
+            let gathered = (self.pebbles[u] < 2 && self.gather(u, v))
+                || (self.pebbles[v] < 2 && self.gather(v, u));
+            if !gathered {
+                return false;
+            }
+        }
+
+        self.pebbles[u] -= 1;
+        self.out_edges[u].push(v);
+        true
+    }
+}
+
+impl Matroid for RigidityMatroid {
+    fn rank(&self, subset: &Set) -> usize {
+        let mut game = PebbleGame::new(self.vertices);
+        subset
+            .bits()
+            .map(|e| self.edges[e])
+            .filter(|&(u, v)| game.try_add(u, v))
+            .count()
+    }
+
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    fn n(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::UniformMatroid;
+
+    #[test]
+    fn complete_graphs() {
+        // K_4 has one edge too many to be independent, and every proper subset is
+        let k4 = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+        let matroid = RigidityMatroid::new(4, &k4).unwrap();
+        assert!(matroid.is_equal(&UniformMatroid::new(5, 6)));
+        assert!(matroid.is_rigid());
+
+        // K_5 minus an edge has nine edges and rank 2 * 5 - 3 = 7
+        let edges = [
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (0, 4),
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (2, 3),
+            (2, 4),
+        ];
+        let matroid = RigidityMatroid::new(5, &edges).unwrap();
+        assert_eq!(matroid.k(), 7);
+        assert_eq!(matroid.check_rank_axioms(), Ok(()));
+    }
+
+    #[test]
+    fn laman_graphs() {
+        // K_{3, 3} is a minimally rigid (Laman) graph
+        let edges: Vec<_> = (0..3).flat_map(|u| (3..6).map(move |v| (u, v))).collect();
+        let matroid = RigidityMatroid::new(6, &edges).unwrap();
+        assert!(matroid.is_independent(&Set::of_size(9)));
+        assert!(matroid.is_rigid());
+
+        // two triangles sharing a vertex are independent, but can rotate around it
+        let bowtie = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)];
+        let matroid = RigidityMatroid::new(5, &bowtie).unwrap();
+        assert_eq!(matroid.k(), 6);
+        assert!(!matroid.is_rigid());
+
+        // parallel edges and loops are dependent
+        let matroid = RigidityMatroid::new(2, &[(0, 1), (0, 1), (1, 1)]).unwrap();
+        assert_eq!(matroid.k(), 1);
+        assert_eq!(matroid.rank(&Set::from(0b100)), 0);
+
+        assert!(RigidityMatroid::new(2, &[(0, 2)]).is_none());
+    }
+}
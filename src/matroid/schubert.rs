@@ -0,0 +1,97 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// The Schubert (or shifted) matroid of a set G, where the bases are the sets that dominate G in
+/// the Gale order: a set B with the same size as G is a basis if the i-th smallest element of B
+/// is at least the i-th smallest element of G, for every i.
+///
+/// This is the transversal matroid of the intervals [g, n - 1] for the elements g in G, and since
+/// the intervals all end in the same element, the rank is found by matching greedily.
+#[derive(Debug, Clone)]
+pub struct SchubertMatroid {
+    n: usize,
+    generator: Vec<usize>,
+}
+
+impl SchubertMatroid {
+    /// The Schubert matroid on n elements given by the generator set.
+    /// Is None if the generator set has elements outside the ground set.
+    pub fn new(n: usize, generator_set: &Set) -> Option<Self> {
+        if !generator_set.difference(&Set::of_size(n)).is_empty() {
+            return None;
+        }
+
+        Some(SchubertMatroid {
+            n,
+            generator: generator_set.bits().collect(),
+        })
+    }
+}
+
+impl Matroid for SchubertMatroid {
+    fn rank(&self, subset: &Set) -> usize {
+        // an element can be matched to any unused interval starting at or before it
+        subset.bits().fold(0, |rank, e| {
+            let available = self.generator.iter().filter(|&&g| g <= e).count();
+            if rank < available {
+                rank + 1
+            } else {
+                rank
+            }
+        })
+    }
+
+    fn k(&self) -> usize {
+        self.generator.len()
+    }
+
+    fn n(&self) -> usize {
+        self.n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::betti_nums::BettiNumbers;
+    use crate::matroid::UniformMatroid;
+    use crate::set::SetIterator;
+
+    #[test]
+    fn gale_order() {
+        let generator = Set::from(vec![1, 2, 4]);
+        let schubert = SchubertMatroid::new(6, &generator).unwrap();
+
+        let dominating: Vec<Set> = SetIterator::new(6)
+            .size_limit(3)
+            .equal()
+            .filter(|b| b.bits().zip(generator.bits()).all(|(e, g)| e >= g))
+            .collect();
+
+        let mut bases = schubert.bases();
+        bases.sort_by_key(|s| usize::from(*s));
+        assert_eq!(bases, dominating);
+        assert_eq!(schubert.check_rank_axioms(), Ok(()));
+    }
+
+    #[test]
+    fn extremes() {
+        // every set dominates the smallest set, and only the largest set dominates itself
+        let smallest = SchubertMatroid::new(6, &Set::of_size(3)).unwrap();
+        assert!(smallest.is_equal(&UniformMatroid::new(3, 6)));
+
+        let largest = SchubertMatroid::new(6, &Set::from(0b111000)).unwrap();
+        assert_eq!(largest.bases(), vec![Set::from(0b111000)]);
+
+        assert!(SchubertMatroid::new(3, &Set::from(0b1001)).is_none());
+    }
+
+    #[test]
+    fn betti_numbers() {
+        let schubert = SchubertMatroid::new(7, &Set::from(vec![0, 2, 5])).unwrap();
+
+        assert!(BettiNumbers::new(&schubert) == BettiNumbers::new_direct(&schubert));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// Ask questions about a set with respect to a matroid.
+/// This is only a different way to call the methods on the matroid.
+///
+/// ```
+/// use matroids::prelude::*;
+///
+/// let matroid = UniformMatroid::new(2, 4);
+/// assert!(Set::from(0b11).is_independent_in(&matroid));
+/// assert!(Set::from(0b111).is_circuit_in(&matroid));
+/// assert_eq!(Set::from(0b1011).rank_in(&matroid), 2);
+/// ```
+pub trait SetMatroidExt {
+    /// checks if the set is independent in the matroid
+    fn is_independent_in<M: Matroid>(&self, matroid: &M) -> bool;
+
+    /// the rank of the set in the matroid
+    fn rank_in<M: Matroid>(&self, matroid: &M) -> usize;
+
+    /// the closure of the set in the matroid
+    fn closure_in<M: Matroid>(&self, matroid: &M) -> Set;
+
+    /// checks if the set is a circuit in the matroid
+    fn is_circuit_in<M: Matroid>(&self, matroid: &M) -> bool;
+}
+
+impl SetMatroidExt for Set {
+    fn is_independent_in<M: Matroid>(&self, matroid: &M) -> bool {
+        matroid.is_independent(self)
+    }
+
+    fn rank_in<M: Matroid>(&self, matroid: &M) -> usize {
+        matroid.rank(self)
+    }
+
+    fn closure_in<M: Matroid>(&self, matroid: &M) -> Set {
+        matroid.closure(self)
+    }
+
+    fn is_circuit_in<M: Matroid>(&self, matroid: &M) -> bool {
+        matroid.is_circuit(self)
+    }
+}
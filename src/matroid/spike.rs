@@ -0,0 +1,158 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// A rank r spike, with or without a tip.
+///
+/// The ground set is the r legs {x_i, y_i}, where x_i is the element 2i and y_i is the element
+/// 2i + 1, and the tip t is the element 2r (when the spike has a tip), so the tipless spike is
+/// the deletion of the tip. Every leg together with the tip is a circuit, and so is the union of
+/// any two legs. Apart from these, the only non-spanning circuits are some of the transversals
+/// (sets with one element from every leg), where any two of the chosen transversals must differ
+/// in at least two legs. With no transversals chosen this is the free spike.
+#[derive(Debug, Clone)]
+pub struct Spike {
+    r: usize,
+    tip: bool,
+    transversals: Vec<Set>,
+}
+
+impl Spike {
+    /// The free spike of rank r with a tip, where every transversal is a basis.
+    /// The rank has to be at least 3.
+    pub fn new(r: usize) -> Self {
+        assert!(r >= 3, "a spike has rank at least 3");
+
+        Spike {
+            r,
+            tip: true,
+            transversals: Vec::new(),
+        }
+    }
+
+    /// Add or remove the tip of the spike.
+    pub fn with_tip(mut self, tip: bool) -> Self {
+        self.tip = tip;
+        self
+    }
+
+    /// Make the given transversals circuit-hyperplanes of the spike.
+    /// Is None if one of the sets is not a transversal of the legs, or if two of them differ in
+    /// only one leg (then the result would not be a matroid).
+    pub fn with_circuits(mut self, transversals: &[Set]) -> Option<Self> {
+        let legs = Set::of_size(2 * self.r);
+        let is_transversal = |t: &Set| {
+            t.difference(&legs).is_empty()
+                && (0..self.r).all(|i| t.intersect(&Set::from(0b11 << (2 * i))).size() == 1)
+        };
+
+        if !transversals.iter().all(is_transversal) {
+            return None;
+        }
+
+        for (i, a) in transversals.iter().enumerate() {
+            for b in transversals.iter().skip(i + 1) {
+                // two transversals differ in half as many legs as they have different elements
+                if a.symmetric_difference(b).size() < 4 {
+                    return None;
+                }
+            }
+        }
+
+        self.transversals = transversals.to_vec();
+        Some(self)
+    }
+
+    /// the tip of the spike, if it has one
+    pub fn tip(&self) -> Option<usize> {
+        self.tip.then_some(2 * self.r)
+    }
+}
+
+impl Matroid for Spike {
+    fn rank(&self, subset: &Set) -> usize {
+        let legs: Vec<usize> = (0..self.r)
+            .map(|i| subset.intersect(&Set::from(0b11 << (2 * i))).size())
+            .collect();
+        let hit = legs.iter().filter(|&&l| l > 0).count();
+        let has_tip = self.tip().is_some_and(|t| subset.contains_element(t));
+
+        if has_tip || legs.contains(&2) {
+            // the tip is spanned by any full leg, and the legs through the tip are points after
+            // contracting it, which are in general position in rank r - 1
+            1 + hit.min(self.r - 1)
+        } else if hit == self.r && self.transversals.contains(subset) {
+            self.r - 1
+        } else {
+            // a partial transversal is independent
+            hit
+        }
+    }
+
+    fn k(&self) -> usize {
+        self.r
+    }
+
+    fn n(&self) -> usize {
+        2 * self.r + usize::from(self.tip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::examples::fano;
+    use crate::matroid::UniformMatroid;
+    use crate::set::SetIterator;
+
+    /// the transversals with an even number of y's
+    fn even_transversals(r: usize) -> Vec<Set> {
+        SetIterator::new(r)
+            .filter(|ys| ys.size() % 2 == 0)
+            .map(|ys| (0..r).map(move |i| 2 * i + usize::from(ys.contains_element(i))))
+            .map(|elements| elements.collect::<Vec<_>>().into())
+            .collect()
+    }
+
+    #[test]
+    fn rank_axioms() {
+        for r in [3, 4] {
+            let spike = Spike::new(r);
+            assert_eq!(spike.check_rank_axioms(), Ok(()));
+            assert_eq!(spike.clone().with_tip(false).check_rank_axioms(), Ok(()));
+
+            let binary = spike.with_circuits(&even_transversals(r)).unwrap();
+            assert_eq!(binary.check_rank_axioms(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn small_spikes() {
+        // the rank 3 binary spike with a tip is the Fano matroid
+        let binary = Spike::new(3).with_circuits(&even_transversals(3)).unwrap();
+        assert!(binary.is_isomorphic(&fano()));
+        assert_eq!(binary.tip(), Some(6));
+
+        // without the tip and the transversal circuits, every set of three elements is a basis
+        let tipless = Spike::new(3).with_tip(false);
+        assert!(tipless.is_equal(&UniformMatroid::new(3, 6)));
+        assert_eq!(tipless.tip(), None);
+
+        // the legs through the tip are circuits, and so are pairs of legs
+        let spike = Spike::new(4);
+        assert!(spike.is_circuit(&Set::from(0b100000011)));
+        assert!(spike.is_circuit(&Set::from(0b000001111)));
+        assert!(spike.is_independent(&Set::from(0b001010101)));
+        assert!(spike.is_connected());
+    }
+
+    #[test]
+    fn invalid_circuits() {
+        // the two transversals only differ in the last leg
+        let circuits = [Set::from(0b010101), Set::from(0b100101)];
+        assert!(Spike::new(3).with_circuits(&circuits).is_none());
+        // not a transversal
+        assert!(Spike::new(3).with_circuits(&[0b000111.into()]).is_none());
+    }
+}
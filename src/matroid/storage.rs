@@ -2,17 +2,88 @@ use std::io::Read;
 use std::io::Write;
 
 use std::error::Error;
+use std::fmt::Display;
 use std::path::Path;
 
 use super::BasesMatroid;
 use super::Matroid;
+use super::UniformMatroid;
+use super::Vamos;
 
 use crate::set::Set;
 
 use postcard::{from_bytes, to_allocvec};
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+/// Files in the current format starts with this byte, followed by the format version.
+/// The legacy format starts with the number of elements (which is a varint smaller than 0x80),
+/// so the two formats can be distinguished by the first byte.
+const FORMAT_MARKER: u8 = 0xff;
+
+/// The version of the storage format written by [`StoredMatroidKind::save`].
+/// Version 1 is the legacy format, where the sets were stored as their raw `usize` content.
+/// Version 2 stores the bases as lists of elements, and version 3 adds compact descriptors of
+/// matroids that are determined by a few parameters (like the uniform matroids).
+pub const FORMAT_VERSION: u8 = 3;
+
+/// The last version where only the bases were stored
+const BASES_ONLY_VERSION: u8 = 2;
+
+/// The serialization format used when writing a matroid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StorageFormat {
+    /// The compact binary format used by the .matroid files
+    #[default]
+    Postcard,
+    /// A human readable JSON document
+    Json,
+}
+
+/// An error when storing or loading a matroid
+#[derive(Debug)]
+pub enum StorageError {
+    /// Reading or writing failed
+    Io(std::io::Error),
+    /// The binary data could not be (de)serialized
+    Postcard(postcard::Error),
+    /// The JSON document could not be (de)serialized
+    Json(serde_json::Error),
+    /// The data is valid, but describes something that is not supported
+    Unsupported(String),
+}
+
+impl Display for StorageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageError::Io(e) => write!(f, "io error: {}", e),
+            StorageError::Postcard(e) => write!(f, "postcard error: {}", e),
+            StorageError::Json(e) => write!(f, "json error: {}", e),
+            StorageError::Unsupported(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for StorageError {}
+
+impl From<std::io::Error> for StorageError {
+    fn from(e: std::io::Error) -> Self {
+        StorageError::Io(e)
+    }
+}
+
+impl From<postcard::Error> for StorageError {
+    fn from(e: postcard::Error) -> Self {
+        StorageError::Postcard(e)
+    }
+}
+
+impl From<serde_json::Error> for StorageError {
+    fn from(e: serde_json::Error) -> Self {
+        StorageError::Json(e)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
 /// A stored matroid. Has to be converted into a [`BasesMatroid`] before usage as a matroid.
 pub struct StoredMatroid {
     // The number of elements in the matroid.
@@ -23,6 +94,199 @@ pub struct StoredMatroid {
     pub bases: Vec<Set>,
 }
 
+/// A stored matroid, either given by a compact descriptor or by its bases.
+/// Only the uniform matroids and the Vamos matroid have compact descriptors, every other matroid
+/// is stored by its bases.
+#[derive(PartialEq, Eq, Debug)]
+pub enum StoredMatroidKind {
+    /// The uniform matroid U(k, n)
+    Uniform { k: usize, n: usize },
+    /// A matroid given by its bases
+    Bases(StoredMatroid),
+    /// The Vamos matroid
+    Vamos,
+}
+
+/// A matroid loaded from a file, of the type it was stored as.
+/// A matroid without a compact descriptor is loaded as a [`BasesMatroid`].
+#[derive(Debug)]
+pub enum LoadedMatroid {
+    Uniform(UniformMatroid),
+    Bases(BasesMatroid),
+    Vamos(Vamos),
+}
+
+/// Call the method on the matroid inside the LoadedMatroid
+macro_rules! dispatch {
+    ($loaded:expr, $m:ident => $call:expr) => {
+        match $loaded {
+            LoadedMatroid::Uniform($m) => $call,
+            LoadedMatroid::Bases($m) => $call,
+            LoadedMatroid::Vamos($m) => $call,
+        }
+    };
+}
+
+// every method that is specialized by one of the types is forwarded, so a loaded matroid is as
+// fast as the matroid that was stored
+impl Matroid for LoadedMatroid {
+    fn rank(&self, subset: &Set) -> usize {
+        dispatch!(self, m => m.rank(subset))
+    }
+
+    fn is_independent(&self, subset: &Set) -> bool {
+        dispatch!(self, m => m.is_independent(subset))
+    }
+
+    fn k(&self) -> usize {
+        dispatch!(self, m => m.k())
+    }
+
+    fn n(&self) -> usize {
+        dispatch!(self, m => m.n())
+    }
+
+    fn is_uniform(&self) -> bool {
+        dispatch!(self, m => m.is_uniform())
+    }
+
+    fn to_stored(&self) -> StoredMatroidKind {
+        dispatch!(self, m => m.to_stored())
+    }
+
+    fn span_and_rank(&self, subset: &Set) -> (Set, usize) {
+        dispatch!(self, m => m.span_and_rank(subset))
+    }
+
+    fn flats_of_rank(&self, rank: usize) -> Vec<Set> {
+        dispatch!(self, m => m.flats_of_rank(rank))
+    }
+
+    fn lattice_rank_sizes(&self) -> Vec<usize> {
+        dispatch!(self, m => m.lattice_rank_sizes())
+    }
+
+    fn circuits(&self) -> Vec<Set> {
+        dispatch!(self, m => m.circuits())
+    }
+
+    fn par_circuits(&self) -> Vec<Set>
+    where
+        Self: Sync,
+    {
+        dispatch!(self, m => m.par_circuits())
+    }
+
+    fn combinatorial_derived(&self) -> super::CombinatorialDerived
+    where
+        Self: Sync + Sized,
+    {
+        dispatch!(self, m => m.combinatorial_derived())
+    }
+}
+
+impl From<StoredMatroidKind> for LoadedMatroid {
+    fn from(stored: StoredMatroidKind) -> Self {
+        match stored {
+            StoredMatroidKind::Uniform { k, n } => {
+                LoadedMatroid::Uniform(UniformMatroid::new(k, n))
+            }
+            StoredMatroidKind::Bases(stored) => LoadedMatroid::Bases(stored.into()),
+            StoredMatroidKind::Vamos => LoadedMatroid::Vamos(Vamos::new()),
+        }
+    }
+}
+
+/// The serialized representation of a stored matroid (version 3).
+/// New variants are only added at the end, so that the earlier ones keep their tags.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+enum Payload {
+    Uniform { k: u16, n: u16 },
+    Bases(ElementArrays),
+    Vamos,
+}
+
+impl From<Payload> for StoredMatroidKind {
+    fn from(payload: Payload) -> Self {
+        match payload {
+            Payload::Uniform { k, n } => StoredMatroidKind::Uniform {
+                k: k.into(),
+                n: n.into(),
+            },
+            Payload::Bases(arrays) => StoredMatroidKind::Bases(arrays.into()),
+            Payload::Vamos => StoredMatroidKind::Vamos,
+        }
+    }
+}
+
+/// A matroid stored as JSON, together with the format version
+#[derive(Serialize, Deserialize)]
+struct JsonDocument {
+    version: u8,
+    matroid: Payload,
+}
+
+/// The serialized representation of the bases of a matroid.
+/// Every basis is stored as a list of its elements, so the format does not depend on the pointer
+/// width of the machine that wrote it.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+struct ElementArrays {
+    n: u16,
+    k: u16,
+    bases: Vec<Vec<u16>>,
+}
+
+/// The legacy (version 1) serialized representation, where the bases are stored as `usize`.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+struct LegacyStoredMatroid {
+    n: usize,
+    k: usize,
+    bases: Vec<Set>,
+}
+
+impl From<&StoredMatroid> for ElementArrays {
+    fn from(stored: &StoredMatroid) -> Self {
+        ElementArrays {
+            n: stored.n as u16,
+            k: stored.k as u16,
+            bases: stored
+                .bases
+                .iter()
+                .map(|base| {
+                    Vec::<usize>::from(base)
+                        .into_iter()
+                        .map(|e| e as u16)
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<ElementArrays> for StoredMatroid {
+    fn from(arrays: ElementArrays) -> Self {
+        StoredMatroid {
+            n: arrays.n.into(),
+            k: arrays.k.into(),
+            bases: arrays
+                .bases
+                .into_iter()
+                .map(|base| base.into_iter().map(usize::from).collect::<Vec<_>>().into())
+                .collect(),
+        }
+    }
+}
+
+impl From<LegacyStoredMatroid> for StoredMatroid {
+    fn from(legacy: LegacyStoredMatroid) -> Self {
+        StoredMatroid {
+            n: legacy.n,
+            k: legacy.k,
+            bases: legacy.bases,
+        }
+    }
+}
+
 impl<M: Matroid> From<&M> for StoredMatroid {
     fn from(matroid: &M) -> Self {
         let bases = matroid.bases();
@@ -40,49 +304,159 @@ impl From<StoredMatroid> for BasesMatroid {
     }
 }
 
-impl StoredMatroid {
+impl StoredMatroidKind {
     /// Store the matroid in a file.
-    #[allow(unused)]
     pub fn to_file(&self, filename: &Path) -> Result<(), Box<dyn Error>> {
         // set the correct extension
         let mut path = filename.to_path_buf();
         path.set_extension("matroid");
 
         let mut file = std::fs::File::create(path)?;
-        self.save(&mut file)
+        Ok(self.save(&mut file)?)
     }
 
     /// Load the matroid from a file.
-    #[allow(unused)]
     pub fn from_file(filename: &Path) -> Result<Self, Box<dyn Error>> {
         // set the extension
         let mut path = filename.to_path_buf();
         path.set_extension("matroid");
 
         let mut file = std::fs::File::open(path)?;
-        Self::load(&mut file)
+        Ok(Self::load(&mut file)?)
+    }
+
+    /// Save the matroid to a writer, in the binary format.
+    pub fn save<W: Write>(&self, writer: &mut W) -> Result<(), StorageError> {
+        self.write_to(writer, StorageFormat::Postcard)
+    }
+
+    /// Load a matroid from a reader, in the binary format.
+    /// All the earlier versions of the format can be loaded.
+    pub fn load<R: Read>(reader: &mut R) -> Result<Self, StorageError> {
+        Self::read_from(reader, StorageFormat::Postcard)
+    }
+
+    /// Write the matroid to a writer in the given format.
+    pub fn write_to<W: Write>(
+        &self,
+        writer: &mut W,
+        format: StorageFormat,
+    ) -> Result<(), StorageError> {
+        let payload = self.payload()?;
+        match format {
+            StorageFormat::Postcard => {
+                // Serialize the matroid
+                let bytes = to_allocvec(&payload)?;
+                // Write the header and the bytes to the writer
+                writer.write_all(&[FORMAT_MARKER, FORMAT_VERSION])?;
+                writer.write_all(&bytes)?;
+            }
+            StorageFormat::Json => {
+                let document = JsonDocument {
+                    version: FORMAT_VERSION,
+                    matroid: payload,
+                };
+                serde_json::to_writer(writer, &document)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Read a matroid in the given format from a reader.
+    pub fn read_from<R: Read>(reader: &mut R, format: StorageFormat) -> Result<Self, StorageError> {
+        let mut bytes = Vec::new();
+        // read the bytes from the reader
+        reader.read_to_end(&mut bytes)?;
+
+        if format == StorageFormat::Json {
+            let document: JsonDocument = serde_json::from_slice(&bytes)?;
+            if document.version != FORMAT_VERSION {
+                return Err(StorageError::Unsupported(format!(
+                    "unsupported matroid format version {}",
+                    document.version
+                )));
+            }
+            return Ok(document.matroid.into());
+        }
+
+        // Deserialize the matroid
+        match bytes.as_slice() {
+            [FORMAT_MARKER, FORMAT_VERSION, payload @ ..] => {
+                Ok(from_bytes::<Payload>(payload)?.into())
+            }
+            [FORMAT_MARKER, BASES_ONLY_VERSION, payload @ ..] => Ok(StoredMatroidKind::Bases(
+                from_bytes::<ElementArrays>(payload)?.into(),
+            )),
+            [FORMAT_MARKER, version, ..] => Err(StorageError::Unsupported(format!(
+                "unsupported matroid format version {}",
+                version
+            ))),
+            legacy => Ok(StoredMatroidKind::Bases(
+                from_bytes::<LegacyStoredMatroid>(legacy)?.into(),
+            )),
+        }
+    }
+
+    /// The serialized representation
+    fn payload(&self) -> Result<Payload, StorageError> {
+        let n = match self {
+            StoredMatroidKind::Uniform { n, .. } => *n,
+            StoredMatroidKind::Bases(stored) => stored.n,
+            StoredMatroidKind::Vamos => 8,
+        };
+        if n > u16::MAX as usize {
+            return Err(StorageError::Unsupported(format!(
+                "cannot store a matroid on {} elements",
+                n
+            )));
+        }
+
+        Ok(match self {
+            StoredMatroidKind::Uniform { k, n } => Payload::Uniform {
+                k: *k as u16,
+                n: *n as u16,
+            },
+            StoredMatroidKind::Bases(stored) => Payload::Bases(ElementArrays::from(stored)),
+            StoredMatroidKind::Vamos => Payload::Vamos,
+        })
+    }
+}
+
+impl StoredMatroid {
+    /// Store the matroid in a file.
+    #[allow(unused)]
+    pub fn to_file(&self, filename: &Path) -> Result<(), Box<dyn Error>> {
+        StoredMatroidKind::Bases(self.clone()).to_file(filename)
+    }
+
+    /// Load the matroid from a file.
+    /// A matroid stored by a compact descriptor is converted to its bases.
+    #[allow(unused)]
+    pub fn from_file(filename: &Path) -> Result<Self, Box<dyn Error>> {
+        Ok(StoredMatroidKind::from_file(filename)?.into())
     }
 
     /// Save the matroid to a writer.
     #[allow(unused)]
     pub fn save<W: Write>(&self, writer: &mut W) -> Result<(), Box<dyn Error>> {
-        // Serialize the matroid
-        let bytes = to_allocvec(self)?;
-        // Write the bytes to the writer
-        writer.write_all(&bytes)?;
-        Ok(())
+        Ok(StoredMatroidKind::Bases(self.clone()).save(writer)?)
     }
 
     /// Load a matroid from a reader.
+    /// A matroid stored by a compact descriptor is converted to its bases.
     #[allow(unused)]
     pub fn load<R: Read>(reader: &mut R) -> Result<Self, Box<dyn Error>> {
-        let mut bytes = Vec::new();
-        // read the bytes from the reader
-        reader.read_to_end(&mut bytes)?;
-        // Deserialize the matroid
-        let stored = from_bytes(&bytes)?;
+        Ok(StoredMatroidKind::load(reader)?.into())
+    }
+}
 
-        Ok(stored)
+impl From<StoredMatroidKind> for StoredMatroid {
+    fn from(stored: StoredMatroidKind) -> Self {
+        match stored {
+            StoredMatroidKind::Uniform { k, n } => StoredMatroid::from(&UniformMatroid::new(k, n)),
+            StoredMatroidKind::Bases(stored) => stored,
+            StoredMatroidKind::Vamos => StoredMatroid::from(&Vamos::new()),
+        }
     }
 }
 
@@ -106,4 +480,159 @@ mod test {
 
         assert_eq!(stored, loaded);
     }
+
+    #[test]
+    fn load_legacy_format() {
+        let matroid = UniformMatroid::new(2, 5);
+        let legacy = LegacyStoredMatroid {
+            n: matroid.n(),
+            k: matroid.k(),
+            bases: matroid.bases(),
+        };
+        let bytes = to_allocvec(&legacy).unwrap();
+
+        let loaded = StoredMatroid::load(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(loaded, StoredMatroid::from(&matroid));
+    }
+
+    #[test]
+    fn width_independent() {
+        // a matroid where some bases only fits in a 64-bit usize
+        let bases: Vec<Set> = vec![[0usize, 39].into(), [1usize, 39].into(), [0usize, 1].into()];
+        let stored = StoredMatroid { n: 40, k: 2, bases };
+
+        let mut bytes = Vec::new();
+        stored.save(&mut bytes).unwrap();
+        assert_eq!(bytes[..2], [FORMAT_MARKER, FORMAT_VERSION]);
+
+        /// How a machine with 32-bit sets would read the data
+        #[derive(Deserialize)]
+        struct Narrow {
+            n: u32,
+            k: u32,
+            bases: Vec<Vec<u32>>,
+        }
+
+        /// The version 3 payload, with the bases read as above
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        enum NarrowPayload {
+            Uniform { k: u32, n: u32 },
+            Bases(Narrow),
+        }
+
+        let narrow = match from_bytes::<NarrowPayload>(&bytes[2..]).unwrap() {
+            NarrowPayload::Bases(narrow) => narrow,
+            NarrowPayload::Uniform { .. } => panic!("stored the bases as a uniform matroid"),
+        };
+        assert_eq!((narrow.n, narrow.k), (40, 2));
+        assert_eq!(narrow.bases, vec![vec![0, 39], vec![1, 39], vec![0, 1]]);
+
+        // the legacy format can not be read with 32-bit sets
+        let legacy = to_allocvec(&LegacyStoredMatroid {
+            n: stored.n,
+            k: stored.k,
+            bases: stored.bases.clone(),
+        })
+        .unwrap();
+        assert!(from_bytes::<(u32, u32, Vec<u32>)>(&legacy).is_err());
+
+        let loaded = StoredMatroid::load(&mut bytes.as_slice()).unwrap();
+        assert_eq!(loaded, stored);
+    }
+
+    #[test]
+    fn load_version_2() {
+        let matroid = UniformMatroid::new(2, 4);
+        let stored = StoredMatroid::from(&matroid);
+
+        let mut bytes = vec![FORMAT_MARKER, BASES_ONLY_VERSION];
+        bytes.extend(to_allocvec(&ElementArrays::from(&stored)).unwrap());
+
+        let loaded = StoredMatroidKind::load(&mut bytes.as_slice()).unwrap();
+        assert_eq!(loaded, StoredMatroidKind::Bases(stored));
+    }
+
+    #[test]
+    fn write_to() {
+        let u36 = UniformMatroid::new(3, 6);
+        let m = crate::matroid::examples::matroid_1();
+
+        for format in [StorageFormat::Postcard, StorageFormat::Json] {
+            let mut bytes = Vec::new();
+            u36.write_to(&mut bytes, format).unwrap();
+            let loaded = crate::matroid::read_matroid(&mut bytes.as_slice(), format).unwrap();
+            assert!(matches!(loaded, LoadedMatroid::Uniform(_)));
+            assert!(loaded.is_equal(&u36));
+
+            let mut bytes = Vec::new();
+            m.write_to(&mut bytes, format).unwrap();
+            let loaded = crate::matroid::read_matroid(&mut bytes.as_slice(), format).unwrap();
+            assert!(matches!(loaded, LoadedMatroid::Bases(_)));
+            assert!(loaded.is_equal(&m));
+        }
+
+        let mut json = Vec::new();
+        u36.write_to(&mut json, StorageFormat::Json).unwrap();
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            r#"{"version":3,"matroid":{"Uniform":{"k":3,"n":6}}}"#
+        );
+
+        assert!(matches!(
+            StoredMatroidKind::read_from(&mut "{}".as_bytes(), StorageFormat::Json),
+            Err(StorageError::Json(_))
+        ));
+        assert!(matches!(
+            StoredMatroidKind::load(&mut [FORMAT_MARKER, 7].as_slice()),
+            Err(StorageError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn compact_uniform() {
+        let u36 = UniformMatroid::new(3, 6);
+        let mut path = temp_dir();
+        path.push(Uuid::new_v4().to_string());
+        u36.save(&path).unwrap();
+
+        // the header, the variant and the two parameters
+        let size = std::fs::metadata(path.with_extension("matroid"))
+            .unwrap()
+            .len();
+        assert_eq!(size, 5);
+
+        let loaded = crate::matroid::load_typed_matroid(&path).unwrap();
+        assert!(matches!(loaded, LoadedMatroid::Uniform(_)));
+        assert!(loaded.is_equal(&u36));
+        // the specialized methods of the uniform matroid are used
+        assert_eq!(loaded.span_and_rank(&0b11.into()), (0b11.into(), 2));
+
+        // load_matroid still gives the bases
+        let bases = crate::matroid::load_matroid(&path).unwrap();
+        assert_eq!(bases.bases().len(), 20);
+        assert!(bases.is_equal(&u36));
+
+        // it may still be read as a list of bases
+        let stored = StoredMatroid::from_file(&path).unwrap();
+        assert_eq!(stored, StoredMatroid::from(&u36));
+    }
+
+    #[test]
+    fn compact_vamos() {
+        let vamos = Vamos::new();
+        let mut bytes = Vec::new();
+        vamos.write_to(&mut bytes, StorageFormat::Postcard).unwrap();
+        // the header and the variant
+        assert_eq!(bytes.len(), 3);
+
+        let loaded =
+            crate::matroid::read_matroid(&mut bytes.as_slice(), StorageFormat::Postcard).unwrap();
+        assert!(matches!(loaded, LoadedMatroid::Vamos(_)));
+        assert_eq!(loaded.circuits(), vamos.circuits());
+
+        let stored = StoredMatroid::load(&mut bytes.as_slice()).unwrap();
+        assert_eq!(stored, StoredMatroid::from(&vamos));
+    }
 }
@@ -0,0 +1,106 @@
+use crate::set::Set;
+
+use super::Matroid;
+
+/// A transversal matroid, given by a presentation: a family of subsets of the ground set.
+/// A set is independent if it is a partial transversal of the family, i.e. if its elements can
+/// be matched to distinct members of the family that contain them.
+///
+/// The rank of a set is the size of a maximum matching between its elements and the members, and
+/// is found with augmenting paths.
+#[derive(Debug, Clone)]
+pub struct TransversalMatroid {
+    n: usize,
+    k: usize,
+    sets: Vec<Set>,
+}
+
+impl TransversalMatroid {
+    /// Create the transversal matroid on n elements with the given presentation.
+    /// Is None if a set has elements outside the ground set.
+    pub fn new(n: usize, sets: Vec<Set>) -> Option<Self> {
+        let ground_set = Set::of_size(n);
+        if sets.iter().any(|s| !s.difference(&ground_set).is_empty()) {
+            return None;
+        }
+
+        let mut matroid = TransversalMatroid { n, k: 0, sets };
+        matroid.k = matroid.rank(&ground_set);
+
+        Some(matroid)
+    }
+
+    /// the sets of the presentation
+    pub fn sets(&self) -> &[Set] {
+        &self.sets
+    }
+
+    /// Try to match the element to a member, where matched[i] is the element matched to member i.
+    /// Members that are already visited in this search are not tried again.
+    fn augment(&self, e: usize, matched: &mut [Option<usize>], visited: &mut [bool]) -> bool {
+        for (i, set) in self.sets.iter().enumerate() {
+            if !set.contains_element(e) || visited[i] {
+                continue;
+            }
+            visited[i] = true;
+
+            let free = match matched[i] {
+                None => true,
+                Some(other) => self.augment(other, matched, visited),
+            };
+            if free {
+                matched[i] = Some(e);
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+impl Matroid for TransversalMatroid {
+    fn rank(&self, subset: &Set) -> usize {
+        let mut matched = vec![None; self.sets.len()];
+        subset
+            .bits()
+            .filter(|&e| self.augment(e, &mut matched, &mut vec![false; self.sets.len()]))
+            .count()
+    }
+
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    fn n(&self) -> usize {
+        self.n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::UniformMatroid;
+
+    #[test]
+    fn uniform() {
+        // k copies of the ground set give U(k, n)
+        let matroid = TransversalMatroid::new(5, vec![Set::of_size(5); 3]).unwrap();
+        assert!(matroid.is_equal(&UniformMatroid::new(3, 5)));
+
+        assert!(TransversalMatroid::new(2, vec![0b100.into()]).is_none());
+    }
+
+    #[test]
+    fn matching() {
+        // the elements 0 and 1 are only in the first set, so they are parallel
+        let sets = vec![0b00011.into(), 0b11100.into(), 0b11100.into()];
+        let matroid = TransversalMatroid::new(5, sets).unwrap();
+        assert_eq!(matroid.k(), 3);
+        assert_eq!(matroid.rank(&0b00011.into()), 1);
+        assert_eq!(matroid.rank(&0b00111.into()), 2);
+        assert_eq!(matroid.rank(&0b01101.into()), 3);
+        assert_eq!(matroid.rank(&0b11100.into()), 2);
+        assert_eq!(matroid.check_rank_axioms(), Ok(()));
+    }
+}
@@ -1,5 +1,7 @@
+use num_integer::binomial;
+
 use crate::matroid::Matroid;
-use crate::set::Set;
+use crate::set::{Set, SetIterator};
 
 #[allow(unused_macros)]
 macro_rules! min {
@@ -54,6 +56,40 @@ impl Matroid for UniformMatroid {
         true
     }
 
+    fn to_stored(&self) -> super::StoredMatroidKind {
+        // the matroid is determined by k and n, so there is no need to store the bases
+        super::StoredMatroidKind::Uniform {
+            k: self.k,
+            n: self.n,
+        }
+    }
+
+    fn span_and_rank(&self, subset: &Set) -> (Set, usize) {
+        // a set of fewer than k elements is a flat, and every other set spans the ground set
+        if subset.size() < self.k {
+            (*subset, subset.size())
+        } else {
+            (Set::of_size(self.n), self.k)
+        }
+    }
+
+    fn flats_of_rank(&self, rank: usize) -> Vec<Set> {
+        use std::cmp::Ordering::*;
+        // every set of size smaller than k is a flat, and the only flat of rank k is the ground set
+        match rank.cmp(&self.k) {
+            Less => SetIterator::new(self.n).size_limit(rank).equal().collect(),
+            Equal => vec![Set::of_size(self.n)],
+            Greater => Vec::new(),
+        }
+    }
+
+    fn lattice_rank_sizes(&self) -> Vec<usize> {
+        (0..self.k)
+            .map(|i| binomial(self.n, i))
+            .chain(std::iter::once(1))
+            .collect()
+    }
+
     fn combinatorial_derived(&self) -> super::CombinatorialDerived
     where
         Self: Sync + Sized,
@@ -77,6 +113,33 @@ mod tests {
         assert_eq!(matroid.circuits().len(), 6);
     }
 
+    #[test]
+    fn flats() {
+        use crate::matroid::BasesMatroid;
+
+        for (k, n) in [(3, 6), (0, 3), (4, 4), (1, 5)] {
+            let matroid = UniformMatroid::new(k, n);
+            // the same matroid, but using the general implementations
+            let general = BasesMatroid::new(matroid.bases(), n, k);
+
+            assert_eq!(matroid.lattice_rank_sizes(), general.lattice_rank_sizes());
+
+            for rank in 0..=(k + 1) {
+                let mut flats = matroid.flats_of_rank(rank);
+                let mut general_flats = general.flats_of_rank(rank);
+                flats.sort_by_key(|s| usize::from(*s));
+                general_flats.sort_by_key(|s| usize::from(*s));
+
+                assert_eq!(flats, general_flats);
+            }
+        }
+
+        assert_eq!(
+            UniformMatroid::new(3, 6).lattice_rank_sizes(),
+            vec![1, 6, 15, 1]
+        );
+    }
+
     #[test]
     fn u36() {
         let matroid = UniformMatroid::new(3, 6);
@@ -87,4 +150,18 @@ mod tests {
         // number of circuits should be 6 choose 4
         assert_eq!(matroid.circuits().len(), 15);
     }
+
+    #[test]
+    fn closure() {
+        use crate::matroid::BasesMatroid;
+
+        for (k, n) in [(3, 6), (0, 3), (4, 4)] {
+            let matroid = UniformMatroid::new(k, n);
+            let general = BasesMatroid::new(matroid.bases(), n, k);
+
+            for s in SetIterator::new(n) {
+                assert_eq!(matroid.span_and_rank(&s), general.span_and_rank(&s));
+            }
+        }
+    }
 }
@@ -12,6 +12,9 @@ macro_rules! max {
     };
 }
 
+/// The only circuits of cardinality 4, every other circuit has cardinality 5
+const SMALL_CIRCUITS: [usize; 5] = [0b00111001, 0b11001001, 0b00001111, 0b11000110, 0b00110110];
+
 fn is_independent(subset: &Set) -> bool {
     use std::cmp::Ordering::*;
     // every set of cardinality smaller than 4 is independent
@@ -20,15 +23,13 @@ fn is_independent(subset: &Set) -> bool {
     match subset.size().cmp(&4) {
         Less => true,
         Greater => false,
-        Equal => !matches!(
-            subset.into(),
-            0b00111001 | 0b11001001 | 0b00001111 | 0b11000110 | 0b00110110
-        ),
+        Equal => !SMALL_CIRCUITS.contains(&subset.into()),
     }
 }
 
 /// The Vamos matroid
 /// see <https://en.wikipedia.org/wiki/Vamos_matroid>
+#[derive(Debug)]
 pub struct Vamos {
     bases: Vec<Set>,
 }
@@ -82,6 +83,35 @@ impl Matroid for Vamos {
     fn is_uniform(&self) -> bool {
         false
     }
+
+    fn to_stored(&self) -> super::StoredMatroidKind {
+        // there is only one Vamos matroid, so there is no need to store the bases
+        super::StoredMatroidKind::Vamos
+    }
+
+    fn circuits(&self) -> Vec<Set> {
+        let small_circuits: Vec<Set> = SMALL_CIRCUITS.iter().map(Set::from).collect();
+
+        // the sets of cardinality 5 are dependent, so they are circuits if they do not contain any
+        // of the small circuits
+        let spanning_circuits = SetIterator::new(8)
+            .size_limit(5)
+            .equal()
+            .filter(|s| !small_circuits.iter().any(|c| c <= s));
+
+        small_circuits
+            .iter()
+            .copied()
+            .chain(spanning_circuits)
+            .collect()
+    }
+
+    fn par_circuits(&self) -> Vec<Set>
+    where
+        Self: Sync,
+    {
+        self.circuits()
+    }
 }
 
 #[cfg(test)]
@@ -92,4 +122,20 @@ mod tests {
     fn correct_number_of_bases() {
         assert_eq!(Vamos::new().bases().len(), 65);
     }
+
+    #[test]
+    fn circuits() {
+        let vamos = Vamos::new();
+        // the same matroid, but with the general circuit calculation
+        let general = crate::matroid::BasesMatroid::new(vamos.bases(), 8, 4);
+
+        let mut circuits = vamos.circuits();
+        let mut general_circuits = general.circuits();
+        circuits.sort_by_key(|s| usize::from(*s));
+        general_circuits.sort_by_key(|s| usize::from(*s));
+
+        assert_eq!(circuits, general_circuits);
+        assert_eq!(circuits.iter().filter(|c| c.size() == 4).count(), 5);
+        assert_eq!(vamos.par_circuits().len(), circuits.len());
+    }
 }
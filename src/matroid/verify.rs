@@ -0,0 +1,341 @@
+//! Checks of the matroid axioms for matroids given by the user.
+//!
+//! The constructors that take bases or circuits directly do not check that they describe a
+//! matroid, so a typo gives a structure that is not a matroid, and everything calculated from it
+//! is meaningless. The functions here find the first violation of the axioms, and report which
+//! sets are involved.
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+
+use crate::set::{Set, SetIterator};
+
+use super::{BasesMatroid, Matroid};
+
+/// A violation of the matroid axioms
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// The set has elements outside the ground set of n elements
+    OutsideGroundSet { set: Set, n: usize },
+    /// The rank of the ground set is not k
+    GroundSetRank { rank: usize, k: usize },
+    /// The rank of the set is larger than its size
+    RankTooLarge { set: Set, rank: usize },
+    /// The subset has larger rank than the superset
+    NotMonotone {
+        subset: Set,
+        subset_rank: usize,
+        superset: Set,
+        superset_rank: usize,
+    },
+    /// r(x) + r(y) < r(x ∪ y) + r(x ∩ y)
+    NotSubmodular {
+        x: Set,
+        y: Set,
+        sum: usize,
+        union_intersection_sum: usize,
+    },
+    /// There are no bases
+    NoBases,
+    /// The basis does not have k elements
+    BasisSize { basis: Set, k: usize },
+    /// The element of a, which is not in b, can not be exchanged for any element of b to give a
+    /// basis
+    BasisExchange { a: Set, b: Set, element: usize },
+    /// The empty set is a circuit
+    EmptyCircuit,
+    /// The circuit is a proper subset of another circuit
+    NestedCircuits { smaller: Set, larger: Set },
+    /// There is no circuit in (a ∪ b) - element, for an element in both of the circuits
+    CircuitElimination { a: Set, b: Set, element: usize },
+    /// The generalized Hamming distance d_h of the dual is larger than n
+    DualHammingDistance { h: usize, d: usize, n: usize },
+    /// The number is neither in the weight hierarchy of the matroid nor of the form n + 1 - d_h
+    /// for the weight hierarchy of the dual, which breaks Wei duality
+    WeiDualityMissing { missing: usize, merged: Vec<usize> },
+    /// The number is in the weight hierarchy of the matroid or of the form n + 1 - d_h for the
+    /// dual more than once, which breaks Wei duality
+    WeiDualityRepeated { repeated: usize, merged: Vec<usize> },
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::OutsideGroundSet { set, n } => {
+                write!(
+                    f,
+                    "{} has elements outside the ground set of {} elements",
+                    set, n
+                )
+            }
+            Violation::GroundSetRank { rank, k } => {
+                write!(f, "the rank of the ground set is {}, but k is {}", rank, k)
+            }
+            Violation::RankTooLarge { set, rank } => {
+                write!(
+                    f,
+                    "r({}) = {} is larger than |{}| = {}",
+                    set,
+                    rank,
+                    set,
+                    set.size()
+                )
+            }
+            Violation::NotMonotone {
+                subset,
+                subset_rank,
+                superset,
+                superset_rank,
+            } => write!(
+                f,
+                "r({}) = {} is larger than r({}) = {}, even though {} is a subset of {}",
+                subset, subset_rank, superset, superset_rank, subset, superset
+            ),
+            Violation::NotSubmodular {
+                x,
+                y,
+                sum,
+                union_intersection_sum,
+            } => write!(
+                f,
+                "r({0}) + r({1}) = {2} is smaller than r({0} ∪ {1}) + r({0} ∩ {1}) = {3}",
+                x, y, sum, union_intersection_sum
+            ),
+            Violation::NoBases => write!(f, "there are no bases"),
+            Violation::BasisSize { basis, k } => {
+                write!(f, "the basis {} does not have {} elements", basis, k)
+            }
+            Violation::BasisExchange { a, b, element } => write!(
+                f,
+                "the element {} of {} can not be exchanged for an element of {}",
+                element, a, b
+            ),
+            Violation::EmptyCircuit => write!(f, "the empty set is a circuit"),
+            Violation::NestedCircuits { smaller, larger } => {
+                write!(
+                    f,
+                    "the circuit {} is contained in the circuit {}",
+                    smaller, larger
+                )
+            }
+            Violation::CircuitElimination { a, b, element } => write!(
+                f,
+                "there is no circuit in the union of {} and {} without the element {}",
+                a, b, element
+            ),
+            Violation::DualHammingDistance { h, d, n } => {
+                write!(f, "d_{} = {} of the dual is larger than n = {}", h, d, n)
+            }
+            Violation::WeiDualityMissing { missing, merged } => {
+                write!(f, "{} is missing from {:?}", missing, merged)
+            }
+            Violation::WeiDualityRepeated { repeated, merged } => {
+                write!(f, "{} is repeated in {:?}", repeated, merged)
+            }
+        }
+    }
+}
+
+impl Error for Violation {}
+
+/// Checks that the rank function satisfies the rank axioms, and that the rank of the ground set
+/// is k.
+///
+/// This calculates the rank of all 2^n subsets, and checks submodularity for all pairs of
+/// subsets, so it takes 4^n steps. It is only feasible for small matroids (n up to about 12).
+pub fn verify_rank_axioms<M: Matroid + ?Sized>(matroid: &M) -> Result<(), Violation> {
+    let n = matroid.n();
+    // the rank of every subset, indexed by the content of the set
+    let ranks: Vec<usize> = SetIterator::new(n).map(|s| matroid.rank(&s)).collect();
+
+    if ranks[(1 << n) - 1] != matroid.k() {
+        return Err(Violation::GroundSetRank {
+            rank: ranks[(1 << n) - 1],
+            k: matroid.k(),
+        });
+    }
+
+    for x in SetIterator::new(n) {
+        let rx = ranks[usize::from(x)];
+        if rx > x.size() {
+            return Err(Violation::RankTooLarge { set: x, rank: rx });
+        }
+
+        // it is enough to check monotonicity when adding single elements
+        for y in (0..n)
+            .filter(|e| !x.contains_element(*e))
+            .map(|e| x.add_element(e))
+        {
+            let ry = ranks[usize::from(y)];
+            if rx > ry {
+                return Err(Violation::NotMonotone {
+                    subset: x,
+                    subset_rank: rx,
+                    superset: y,
+                    superset_rank: ry,
+                });
+            }
+        }
+    }
+
+    for x in 0..(1usize << n) {
+        for y in (x + 1)..(1usize << n) {
+            let lhs = ranks[x | y] + ranks[x & y];
+            let rhs = ranks[x] + ranks[y];
+            if lhs > rhs {
+                return Err(Violation::NotSubmodular {
+                    x: Set::from(x),
+                    y: Set::from(y),
+                    sum: rhs,
+                    union_intersection_sum: lhs,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the bases the matroid was created with are the bases of a matroid: there is at
+/// least one, they all have k elements in the ground set, and for bases A and B and an element a
+/// of A not in B, there is an element b of B not in A such that A - a + b is a basis.
+pub fn verify_basis_exchange(matroid: &BasesMatroid) -> Result<(), Violation> {
+    let bases = matroid.stored_bases();
+    let ground_set = Set::of_size(matroid.n());
+
+    if bases.is_empty() {
+        return Err(Violation::NoBases);
+    }
+    for &basis in bases {
+        if !basis.difference(&ground_set).is_empty() {
+            return Err(Violation::OutsideGroundSet {
+                set: basis,
+                n: matroid.n(),
+            });
+        }
+        if basis.size() != matroid.k() {
+            return Err(Violation::BasisSize {
+                basis,
+                k: matroid.k(),
+            });
+        }
+    }
+
+    let lookup: HashSet<Set> = bases.iter().copied().collect();
+    for &a in bases {
+        for &b in bases {
+            let only_in_b = b.difference(&a);
+            for element in a.difference(&b).bits() {
+                let without = a.remove_element(element);
+                if !only_in_b
+                    .bits()
+                    .any(|e| lookup.contains(&without.add_element(e)))
+                {
+                    return Err(Violation::BasisExchange { a, b, element });
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the sets are the circuits of a matroid on n elements: the empty set is not a
+/// circuit, no circuit properly contains another, and for circuits A and B and an element e in
+/// both, there is a circuit contained in (A ∪ B) - e.
+pub fn verify_circuit_axioms(circuits: &[Set], n: usize) -> Result<(), Violation> {
+    let ground_set = Set::of_size(n);
+    for &circuit in circuits {
+        if circuit.is_empty() {
+            return Err(Violation::EmptyCircuit);
+        }
+        if !circuit.difference(&ground_set).is_empty() {
+            return Err(Violation::OutsideGroundSet { set: circuit, n });
+        }
+    }
+
+    for &a in circuits {
+        for &b in circuits.iter().filter(|&&b| b != a) {
+            if a <= b {
+                return Err(Violation::NestedCircuits {
+                    smaller: a,
+                    larger: b,
+                });
+            }
+
+            let union = a.union(&b);
+            for element in a.intersect(&b).bits() {
+                let rest = union.remove_element(element);
+                if !circuits.iter().any(|c| *c <= rest) {
+                    return Err(Violation::CircuitElimination { a, b, element });
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matroid::examples::fano;
+    use crate::matroid::UniformMatroid;
+
+    #[test]
+    fn bases() {
+        let u24 = UniformMatroid::new(2, 4);
+        assert_eq!(
+            verify_basis_exchange(&BasesMatroid::new(u24.bases(), 4, 2)),
+            Ok(())
+        );
+
+        // {0, 1} and {2, 3} are the only bases, so 0 can not be exchanged for 2 or 3
+        let bases = vec![0b0011.into(), 0b1100.into()];
+        assert_eq!(
+            verify_basis_exchange(&BasesMatroid::new(bases, 4, 2)),
+            Err(Violation::BasisExchange {
+                a: 0b0011.into(),
+                b: 0b1100.into(),
+                element: 0
+            })
+        );
+
+        assert_eq!(
+            verify_basis_exchange(&BasesMatroid::new(Vec::new(), 4, 2)),
+            Err(Violation::NoBases)
+        );
+    }
+
+    #[test]
+    fn circuits() {
+        let fano = fano();
+        assert_eq!(verify_circuit_axioms(&fano.circuits(), 7), Ok(()));
+
+        // the lines {0, 1, 2} and {0, 3, 4} of a plane, without the circuits of size four
+        let lines = [0b00111.into(), 0b11001.into()];
+        assert_eq!(
+            verify_circuit_axioms(&lines, 5),
+            Err(Violation::CircuitElimination {
+                a: 0b00111.into(),
+                b: 0b11001.into(),
+                element: 0
+            })
+        );
+
+        let nested = [0b011.into(), 0b111.into()];
+        assert_eq!(
+            verify_circuit_axioms(&nested, 3),
+            Err(Violation::NestedCircuits {
+                smaller: 0b011.into(),
+                larger: 0b111.into()
+            })
+        );
+        assert_eq!(
+            verify_circuit_axioms(&[Set::empty()], 3),
+            Err(Violation::EmptyCircuit)
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! Parallel iteration, with a sequential fallback when the `parallel` feature is disabled.
+//!
+//! With the feature enabled, this re-exports the traits from rayon. Without it, traits with the
+//! same method names are provided that returns ordinary iterators, so the call sites are the same
+//! in both cases.
+
+#[cfg(feature = "parallel")]
+pub(crate) use rayon::iter::{IntoParallelIterator, ParallelBridge, ParallelIterator};
+
+/// Sequential replacement for [`rayon::iter::ParallelBridge`]
+#[cfg(not(feature = "parallel"))]
+pub(crate) trait ParallelBridge: Iterator + Sized {
+    fn par_bridge(self) -> Self {
+        self
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<I: Iterator> ParallelBridge for I {}
+
+/// Sequential replacement for [`rayon::iter::IntoParallelIterator`]
+#[cfg(not(feature = "parallel"))]
+pub(crate) trait IntoParallelIterator: IntoIterator + Sized {
+    fn into_par_iter(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<I: IntoIterator> IntoParallelIterator for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_results() {
+        let v: Vec<usize> = (0..1000).collect();
+        let sum: usize = v.into_par_iter().filter(|x| x % 3 == 0).sum();
+        let bridged: usize = (0..1000).par_bridge().filter(|x| x % 3 == 0).sum();
+
+        assert_eq!(sum, bridged);
+        assert_eq!(sum, (0..1000).filter(|x| x % 3 == 0).sum::<usize>());
+    }
+
+    #[test]
+    #[cfg(not(feature = "parallel"))]
+    fn single_threaded_circuits() {
+        use std::collections::HashSet;
+        use std::sync::Mutex;
+
+        use crate::matroid::{Matroid, UniformMatroid};
+
+        let threads = Mutex::new(HashSet::new());
+        let matroid = UniformMatroid::new(3, 6);
+
+        let circuits: Vec<_> = crate::set::SetIterator::new(matroid.n())
+            .par_bridge()
+            .filter(|s| {
+                threads.lock().unwrap().insert(std::thread::current().id());
+                matroid.is_circuit(s)
+            })
+            .collect();
+
+        assert_eq!(threads.lock().unwrap().len(), 1);
+        assert_eq!(circuits, matroid.circuits());
+        assert_eq!(matroid.par_circuits(), matroid.circuits());
+        assert_eq!(matroid.circuits().len(), 15);
+    }
+}
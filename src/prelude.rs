@@ -0,0 +1,14 @@
+//! The most commonly used traits and types, to be glob imported.
+//!
+//! ```
+//! use matroids::prelude::*;
+//!
+//! let matroid = UniformMatroid::new(3, 5);
+//! let set = Set::from(0b111);
+//! assert_eq!(set.rank_in(&matroid), 3);
+//! ```
+
+pub use crate::matroid::{
+    BasesMatroid, MatrixMatroid, Matroid, SetMatroidExt, UniformMatroid, Vamos,
+};
+pub use crate::set::{Set, SetIterator};
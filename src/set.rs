@@ -39,7 +39,8 @@ impl Set {
     /// assert_eq!(set.leftmost_element(), 3);
     /// ```
     pub fn leftmost_element(&self) -> usize {
-        (self.content as f32).log2() as usize
+        // the empty set is treated as if it had the element 0
+        (usize::BITS - 1).saturating_sub(self.content.leading_zeros()) as usize
     }
 
     #[inline]
@@ -119,6 +120,28 @@ impl Set {
         self.content & (1 << element) != 0
     }
 
+    /// iterate over the elements of the set in ascending order
+    ///
+    /// This only visits the elements in the set, so it takes time proportional to the size of the
+    /// set, not to the index of the leftmost element.
+    /// ```
+    /// use matroids::set::Set;
+    /// let set = Set::from(0b1000100010);
+    /// assert_eq!(set.bits().collect::<Vec<_>>(), vec![1, 5, 9]);
+    /// ```
+    pub fn bits(&self) -> impl Iterator<Item = usize> {
+        let mut content = self.content;
+        std::iter::from_fn(move || {
+            if content == 0 {
+                return None;
+            }
+            // isolate and remove the lowest set bit
+            let lowest = content & content.wrapping_neg();
+            content ^= lowest;
+            Some(lowest.trailing_zeros() as usize)
+        })
+    }
+
     /// If self is a subset of set, then extend self to be of the format of set
     /// assumes that self.size() <= set.size()
     pub fn extend(&self, set: &Self) -> Self {
@@ -142,16 +165,60 @@ impl Set {
         Self { content }
     }
 
-    /// Take the union of the sets that are chosen by self
+    /// The complement of self in the ground set {0, 1, ..., n - 1}
+    /// ```
+    /// use matroids::set::Set;
+    /// assert_eq!(Set::from(0b0101).complement(4), Set::from(0b1010));
+    /// ```
+    pub fn complement(&self, n: usize) -> Self {
+        Set::of_size(n).difference(self)
+    }
+
+    /// The set as a vector of n booleans, where element i is true if i is in the set
+    /// ```
+    /// use matroids::set::Set;
+    /// let set = Set::from(0b1101);
+    /// let bits = set.to_bool_vec(5);
+    /// assert_eq!(bits, vec![true, false, true, true, false]);
+    /// assert_eq!(Set::from_bool_vec(&bits), set);
+    /// ```
+    pub fn to_bool_vec(&self, n: usize) -> Vec<bool> {
+        (0..n).map(|i| self.contains_element(i)).collect()
+    }
+
+    /// The set containing i if bits\[i\] is true
+    pub fn from_bool_vec(bits: &[bool]) -> Self {
+        bits.iter()
+            .enumerate()
+            .filter(|(_, &b)| b)
+            .fold(Set::empty(), |acc, (i, _)| acc.add_element(i))
+    }
+
+    /// Take the union of the sets that are chosen by self.
+    /// Requires that self.leftmost_element() < sets.len(), i.e. that every element of self is an
+    /// index into sets.
     pub fn union_of_sets(&self, sets: &[Set]) -> Self {
+        debug_assert!(
+            self.is_empty() || self.leftmost_element() < sets.len(),
+            "the set {} chooses an index outside the {} sets",
+            self,
+            sets.len()
+        );
         (0..=self.leftmost_element())
             .filter(|i| self.contains_element(*i))
             .fold(Set::empty(), |acc, i| acc.union(&sets[i]))
     }
 
     /// Take the kirkhoff sum, also known as the iterated symmetric difference, of the sets that
-    /// are chosen by self
+    /// are chosen by self.
+    /// Requires that self.leftmost_element() < sets.len().
     pub fn kirkhoff_sum(&self, sets: &[Set]) -> Self {
+        debug_assert!(
+            self.is_empty() || self.leftmost_element() < sets.len(),
+            "the set {} chooses an index outside the {} sets",
+            self,
+            sets.len()
+        );
         (0..=self.leftmost_element())
             .filter(|i| self.contains_element(*i))
             .fold(Set::empty(), |acc, i| acc.symmetric_difference(&sets[i]))
@@ -496,6 +563,39 @@ mod tests {
         assert_eq!(a.leftmost_element(), 2);
         assert_eq!(b.leftmost_element(), 0);
         assert_eq!(c.leftmost_element(), 3);
+
+        // 25 elements can not be represented exactly as a f32
+        let wide = Set::of_size(25);
+        assert_eq!(wide.leftmost_element(), 24);
+        assert_eq!(Set::empty().leftmost_element(), 0);
+    }
+
+    #[test]
+    fn complement() {
+        let a = Set::from(0b00101);
+
+        assert_eq!(a.complement(5), Set::from(0b11010));
+        assert_eq!(a.complement(5).complement(5), a);
+        assert_eq!(Set::empty().complement(3), Set::of_size(3));
+    }
+
+    #[test]
+    fn union_of_sets() {
+        let sets = [Set::from(0b0011), Set::from(0b0110), Set::from(0b1000)];
+
+        assert_eq!(Set::from(0b101).union_of_sets(&sets), Set::from(0b1011));
+        assert_eq!(Set::from(0b011).union_of_sets(&sets), Set::from(0b0111));
+        assert_eq!(Set::empty().union_of_sets(&sets), Set::empty());
+        assert_eq!(Set::empty().union_of_sets(&[]), Set::empty());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "outside the 2 sets")]
+    fn union_of_sets_out_of_range() {
+        let sets = [Set::from(0b0011), Set::from(0b0110)];
+
+        Set::from(0b100).union_of_sets(&sets);
     }
 
     #[test]
@@ -525,6 +625,22 @@ mod tests {
         assert_eq!(b.extend(&a), c);
     }
 
+    #[test]
+    fn bits() {
+        let sets = [
+            Set::empty(),
+            Set::from(1),
+            Set::from(1 << (usize::BITS - 1)),
+            Set::from((1 << 40) | (1 << 3)),
+            Set::from([0usize, 17, 33, 63]),
+            Set::of_size(12),
+        ];
+
+        for set in sets {
+            assert_eq!(set.bits().collect::<Vec<_>>(), Vec::<usize>::from(set));
+        }
+    }
+
     #[test]
     fn iterator_all() {
         let mut iter = SetIterator::new(3);
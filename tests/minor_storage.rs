@@ -0,0 +1,54 @@
+use matroids::matroid::{load_matroid, Matroid, UniformMatroid, Vamos};
+use matroids::set::Set;
+
+use std::env::temp_dir;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+fn temp_path() -> PathBuf {
+    let mut path = temp_dir();
+    path.push(Uuid::new_v4().to_string());
+    path
+}
+
+fn assert_round_trip<M: Matroid>(matroid: &M, delete: &Set, contract: &Set) {
+    let minor = matroid.minor(delete, contract);
+    let path = temp_path();
+    minor.save(&path).unwrap();
+    let loaded = load_matroid(&path).unwrap();
+
+    let n = matroid.n() - delete.size() - contract.size();
+    let k = matroid.rank(&Set::of_size(matroid.n()).difference(delete)) - matroid.rank(contract);
+    assert_eq!((loaded.n(), loaded.k()), (n, k));
+    assert!(loaded.is_equal(&minor));
+}
+
+#[test]
+fn uniform_minor_round_trip() {
+    let u37 = UniformMatroid::new(3, 7);
+    assert_round_trip(&u37, &0b0000101.into(), &0b1000000.into());
+    assert_round_trip(&u37, &Set::empty(), &0b0110000.into());
+    assert_round_trip(&u37, &0b1111000.into(), &Set::empty());
+}
+
+#[test]
+fn vamos_minor_round_trip() {
+    let vamos = Vamos::default();
+    assert_round_trip(&vamos, &0b00000001.into(), &0b10000000.into());
+    assert_round_trip(&vamos, &Set::empty(), &0b00000011.into());
+}
+
+#[test]
+fn minor_of_loaded_matroid() {
+    // taking a minor commutes with storing the matroid
+    let u36 = UniformMatroid::new(3, 6);
+    let path = temp_path();
+    u36.save(&path).unwrap();
+    let loaded = load_matroid(&path).unwrap();
+
+    let delete = Set::from(0b000001);
+    let contract = Set::from(0b010000);
+    assert!(loaded
+        .minor(&delete, &contract)
+        .is_equal(&u36.minor(&delete, &contract)));
+}